@@ -0,0 +1,119 @@
+// Push/pop throughput of three ways to build a stack:
+//
+//   cargo bench --bench stack
+//
+// - rust_tutorial::collections::Stack, backed by a Vec (with and without with_capacity)
+// - a stack backed by a VecDeque
+// - a singly linked list of Box<Node>, the "textbook" stack
+//
+// Criterion warms up, collects many samples and reports confidence intervals, and
+// it remembers the last run, so a second `cargo bench` tells you what changed.
+// The HTML report ends up in target/criterion/report/index.html.
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use rust_tutorial::collections::Stack;
+use std::collections::VecDeque;
+use std::hint::black_box;
+
+// The three stacks only need to agree on push and pop
+trait Lifo {
+    fn push(&mut self, item: u64);
+    fn pop(&mut self) -> Option<u64>;
+}
+
+impl Lifo for Stack<u64> {
+    fn push(&mut self, item: u64) {
+        Stack::push(self, item);
+    }
+
+    fn pop(&mut self) -> Option<u64> {
+        Stack::pop(self)
+    }
+}
+
+struct DequeStack {
+    items: VecDeque<u64>,
+}
+
+impl Lifo for DequeStack {
+    fn push(&mut self, item: u64) {
+        self.items.push_back(item);
+    }
+
+    fn pop(&mut self) -> Option<u64> {
+        self.items.pop_back()
+    }
+}
+
+// Every push is a separate heap allocation, and the nodes end up scattered in memory
+struct LinkedStack {
+    head: Option<Box<Node>>,
+}
+
+struct Node {
+    item: u64,
+    next: Option<Box<Node>>,
+}
+
+impl Lifo for LinkedStack {
+    fn push(&mut self, item: u64) {
+        let next = self.head.take();
+        self.head = Some(Box::new(Node { item, next }));
+    }
+
+    fn pop(&mut self) -> Option<u64> {
+        let node = self.head.take()?;
+        self.head = node.next;
+        Some(node.item)
+    }
+}
+
+// Fill one stack with `size` items, then pop them all
+fn push_then_pop<S: Lifo>(mut stack: S, size: usize) {
+    for n in 0..size as u64 {
+        stack.push(black_box(n));
+    }
+    while let Some(item) = stack.pop() {
+        black_box(item);
+    }
+}
+
+fn stacks(c: &mut Criterion) {
+    let mut group = c.benchmark_group("push then pop");
+
+    // 100 items: every reallocation copies the items pushed so far, and a small stack
+    // spends a big share of its time growing, so with_capacity's single up-front
+    // allocation clearly wins.
+    // 100_000 items: growth doubles the buffer, so there are few reallocations, and
+    // the allocator can often grow a big buffer in place. The gap mostly disappears.
+    for size in [100, 100_000] {
+        // Report items per second, so both sizes can be compared directly
+        group.throughput(Throughput::Elements(size as u64));
+
+        group.bench_with_input(BenchmarkId::new("Stack::new", size), &size, |b, &size| {
+            b.iter(|| push_then_pop(Stack::<u64>::new(), size))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("Stack::with_capacity", size),
+            &size,
+            |b, &size| b.iter(|| push_then_pop(Stack::<u64>::with_capacity(size), size)),
+        );
+        group.bench_with_input(BenchmarkId::new("VecDeque", size), &size, |b, &size| {
+            b.iter(|| {
+                let stack = DequeStack {
+                    items: VecDeque::new(),
+                };
+                push_then_pop(stack, size)
+            })
+        });
+        // Either way the linked list pays for one allocation per push and one free per
+        // pop, and its nodes are scattered around memory instead of sitting side by side
+        group.bench_with_input(BenchmarkId::new("linked list", size), &size, |b, &size| {
+            b.iter(|| push_then_pop(LinkedStack { head: None }, size))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, stacks);
+criterion_main!(benches);
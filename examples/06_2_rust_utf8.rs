@@ -0,0 +1,51 @@
+// 🦀 Rust UTF-8 Exercises
+// Type out each exercise to build muscle memory!
+// Stuck on one? `cargo run -- hint 06_2` shows a hint; add `--level 2` or `--level 3` for more.
+// Follow-up to 06_rust_strings_vs_str_slices.rs. A String is UTF-8 bytes, and
+// `&text[0..4]` slices those bytes. With accents or emoji, one character can be
+// 2, 3 or 4 bytes, and slicing through the middle of one panics.
+// The fixes live in the library: src/strutil.rs.
+
+// Nothing uses these until you type the exercises in
+#![allow(unused_imports, unused_variables)]
+
+use rust_tutorial::strutil;
+
+fn main() {
+    let text = "héllo";
+
+    println!("=== Exercise 1: Bytes vs Characters ===");
+    // TODO: len() counts bytes, chars().count() counts characters. Print both for text.
+
+    // TODO: Print every character with the byte offset where it starts, and how many
+    // bytes it takes (char_indices and len_utf8)
+
+    println!("\n=== Exercise 2: The Panic ===");
+    // TODO: 0..1 is fine, but byte 2 is inside 'é' (uncomment to see the panic at runtime)
+    // println!("{}", &text[0..2]); // Panics: byte index 2 is not a char boundary
+
+    // TODO: is_char_boundary tells you whether a byte offset is safe to cut at.
+    // Check offsets 0 to 3.
+
+    println!("\n=== Exercise 3: safe_slice Returns an Option ===");
+    // TODO: Same byte ranges, but None instead of a panic. Try 0..2, 0..3 and 3..99.
+
+    // TODO: Combine with unwrap_or for a fallback of "(cut in half)"
+
+    println!("\n=== Exercise 4: Counting in Characters ===");
+    // TODO: char_at and char_slice take character positions, not bytes. Get the
+    // second char, the first two chars and the (missing) tenth char.
+
+    println!("\n=== Exercise 5: When a char Isn't a Character ===");
+    // TODO: Make 'combined', the same word but with the accent as a separate combining
+    // char (U+0301). Compare its chars().count() with strutil::grapheme_count.
+
+    // TODO: Emoji can be several chars too: a flag is two, a family is five.
+    // Do the same for "🇯🇵", "👍🏽" and "👨\u{200D}👩\u{200D}👧".
+
+    println!("\n=== Exercise 6: Truncating for Display ===");
+    // TODO: Cut combined down to 3 visible characters without breaking the accent,
+    // then print all of its graphemes as a Vec
+
+    println!("\n✅ All exercises complete!");
+}
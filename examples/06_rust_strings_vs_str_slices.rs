@@ -1,3 +1,5 @@
+// `&text[..]` below is redundant on purpose, it shows the full-range slice
+#[allow(clippy::redundant_slicing)]
 fn main() {
     // Experiment 1: See ownership in action
     let a = String::from("Hello");
@@ -27,6 +29,8 @@ fn main() {
     println!("{}", x); // Still works!
 
     // Experiment 4: Playing with slices
+    // These ranges count bytes. That's fine for ASCII text like this, but see
+    // 06_2_rust_utf8.rs for what happens with "héllo".
     let text = "Rust is awesome!";
     println!("{}", &text[0..4]); // "Rust"
     println!("{}", &text[5..7]); // "is"
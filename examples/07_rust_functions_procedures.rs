@@ -6,6 +6,8 @@ fn add(num_a: f32, num_b: f32) -> f32 {
     }
 }
 
+// `-> ()` is spelled out on purpose: a procedure returns the unit type
+#[allow(clippy::unused_unit)]
 fn say_hi() -> () {
     println!("I am saying Hi");
 }
@@ -16,3 +18,22 @@ fn main() {
 
     say_hi();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_two_numbers() {
+        assert_eq!(add(1.0, 2.0), 3.0);
+        assert_eq!(add(-1.5, 0.5), -1.0);
+    }
+
+    #[test]
+    fn zero_on_either_side_gives_zero() {
+        // Not real addition: this function treats 0 as "no value"
+        assert_eq!(add(0.0, 5.0), 0.0);
+        assert_eq!(add(5.0, 0.0), 0.0);
+        assert_eq!(add(0.0, 0.0), 0.0);
+    }
+}
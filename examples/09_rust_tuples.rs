@@ -1,12 +1,16 @@
-fn test_match(point: (i32, i32)) {
+fn describe_point(point: (i32, i32)) -> String {
     match point {
-        (0, 0) => println!("Origin"),
-        (0, y) => println!("On Y-axis at {}", y),
-        (x, 0) => println!("On X-axis at {}", x),
-        (x, y) => println!("Point at ({}, {})", x, y),
+        (0, 0) => String::from("Origin"),
+        (0, y) => format!("On Y-axis at {}", y),
+        (x, 0) => format!("On X-axis at {}", x),
+        (x, y) => format!("Point at ({}, {})", x, y),
     }
 }
 
+fn test_match(point: (i32, i32)) {
+    println!("{}", describe_point(point));
+}
+
 fn main() {
     let some_tuples = (1, 2, 3, 4);
 
@@ -30,3 +34,21 @@ fn main() {
 
     test_match((0, some_tuples.2));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn origin_and_axes() {
+        assert_eq!(describe_point((0, 0)), "Origin");
+        assert_eq!(describe_point((0, -3)), "On Y-axis at -3");
+        assert_eq!(describe_point((7, 0)), "On X-axis at 7");
+    }
+
+    #[test]
+    fn anywhere_else() {
+        assert_eq!(describe_point((2, 3)), "Point at (2, 3)");
+        assert_eq!(describe_point((-1, -1)), "Point at (-1, -1)");
+    }
+}
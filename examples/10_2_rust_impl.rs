@@ -54,3 +54,34 @@ fn main() {
     let user3: User = User::default_user();
     user3.display();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_fills_every_field() {
+        let user = User::new(String::from("Bob"), String::from("bob@gmail.com"), 25);
+
+        assert_eq!(user.username, "Bob");
+        assert_eq!(user.email, "bob@gmail.com");
+        assert_eq!(user.age, 25);
+    }
+
+    #[test]
+    fn update_email_replaces_only_the_email() {
+        let mut user = User::new(String::from("Alice"), String::from("old@example.com"), 29);
+        user.update_email(String::from("new@example.com"));
+
+        assert_eq!(user.email, "new@example.com");
+        assert_eq!(user.username, "Alice");
+    }
+
+    #[test]
+    fn default_user_is_a_guest() {
+        let user = User::default_user();
+
+        assert_eq!(user.username, "Guest");
+        assert_eq!(user.age, 0);
+    }
+}
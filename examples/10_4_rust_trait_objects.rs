@@ -0,0 +1,225 @@
+// Follow-up to 10_3_rust_trait.rs: the same Drawable idea, but now with
+// default methods, supertraits and trait objects (dynamic dispatch)
+
+// A supertrait: anything Drawable must also be Describable
+trait Describable {
+    fn name(&self) -> String;
+
+    // Default method: implementors get this for free, but may override it
+    fn describe(&self) -> String {
+        format!("This is a {}", self.name())
+    }
+}
+
+// `Drawable: Describable` means "to implement Drawable, implement Describable first"
+trait Drawable: Describable {
+    fn draw(&self);
+
+    fn area(&self) -> f64;
+
+    // Default methods can call required methods, including the supertrait's
+    fn draw_with_label(&self) {
+        println!("[{}]", self.name());
+        self.draw();
+    }
+}
+
+struct Circle {
+    radius: f64,
+}
+
+struct Square {
+    side: f64,
+}
+
+struct Triangle {
+    base: f64,
+    height: f64,
+}
+
+impl Describable for Circle {
+    fn name(&self) -> String {
+        String::from("circle")
+    }
+}
+
+impl Drawable for Circle {
+    fn draw(&self) {
+        println!("Drawing a circle with radius {}", self.radius);
+    }
+
+    fn area(&self) -> f64 {
+        std::f64::consts::PI * self.radius * self.radius
+    }
+}
+
+impl Describable for Square {
+    fn name(&self) -> String {
+        String::from("square")
+    }
+
+    // Overriding the default method
+    fn describe(&self) -> String {
+        format!("A square with four sides of {}", self.side)
+    }
+}
+
+impl Drawable for Square {
+    fn draw(&self) {
+        println!("Drawing a square with side {}", self.side);
+    }
+
+    fn area(&self) -> f64 {
+        self.side * self.side
+    }
+}
+
+impl Describable for Triangle {
+    fn name(&self) -> String {
+        String::from("triangle")
+    }
+}
+
+impl Drawable for Triangle {
+    fn draw(&self) {
+        println!(
+            "Drawing a triangle with base {} and height {}",
+            self.base, self.height
+        );
+    }
+
+    fn area(&self) -> f64 {
+        0.5 * self.base * self.height
+    }
+
+    // Overriding a Drawable default method
+    fn draw_with_label(&self) {
+        println!("[triangle, area {}]", self.area());
+        self.draw();
+    }
+}
+
+// Static dispatch (generics): the compiler makes a separate copy of this function
+// for every concrete type it's called with (monomorphization). No runtime cost,
+// but every item in one call has to be the SAME type.
+fn render_static<T: Drawable>(shape: &T) {
+    shape.draw_with_label();
+}
+
+// Dynamic dispatch (trait objects): one copy of the function. At runtime `dyn Drawable`
+// looks up the right `draw` in a vtable. Small runtime cost, but the types can differ.
+fn render_dynamic(shape: &dyn Drawable) {
+    shape.draw_with_label();
+}
+
+// A scene holds shapes of different types. Box<dyn Drawable> has a known size
+// (a pointer + a vtable pointer) even though Circle and Square don't.
+struct Scene {
+    shapes: Vec<Box<dyn Drawable>>,
+}
+
+impl Scene {
+    fn new() -> Scene {
+        Scene { shapes: Vec::new() }
+    }
+
+    fn add(&mut self, shape: Box<dyn Drawable>) {
+        self.shapes.push(shape);
+    }
+
+    fn render(&self) {
+        for shape in &self.shapes {
+            shape.draw_with_label();
+        }
+    }
+
+    fn total_area(&self) -> f64 {
+        self.shapes.iter().map(|shape| shape.area()).sum()
+    }
+}
+
+fn main() {
+    let circle: Circle = Circle { radius: 5.0 };
+    let square: Square = Square { side: 3.0 };
+
+    // Default vs overridden methods
+    println!("{}", circle.describe());
+    println!("{}", square.describe());
+
+    // Static dispatch: render_static::<Circle> and render_static::<Square> are two functions
+    println!("\n-- static dispatch --");
+    render_static(&circle);
+    render_static(&square);
+
+    // This doesn't compile: a Vec<T> holds one type only
+    // let shapes = vec![circle, square]; // Error: expected `Circle`, found `Square`
+
+    // Dynamic dispatch: one function, chosen at runtime through the vtable
+    println!("\n-- dynamic dispatch --");
+    render_dynamic(&circle);
+    render_dynamic(&square);
+
+    // A heterogeneous collection
+    println!("\n-- scene --");
+    let mut scene: Scene = Scene::new();
+    scene.add(Box::new(circle));
+    scene.add(Box::new(square));
+    scene.add(Box::new(Triangle {
+        base: 6.0,
+        height: 4.0,
+    }));
+    scene.render();
+    println!("Total area: {:.2}", scene.total_area());
+
+    // Supertrait methods are callable through the trait object too
+    for shape in &scene.shapes {
+        println!("{}", shape.describe());
+    }
+
+    // When to use which?
+    // - Generics (T: Drawable): the type is known at compile time, you want maximum
+    //   speed, or you need to return the same concrete type back.
+    // - Trait objects (dyn Drawable): you need a mix of types in one collection,
+    //   the set of types is open-ended (plugins), or you want smaller binaries.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn areas() {
+        assert_eq!(Square { side: 3.0 }.area(), 9.0);
+        assert_eq!(
+            Triangle {
+                base: 6.0,
+                height: 4.0
+            }
+            .area(),
+            12.0
+        );
+        assert_eq!(Circle { radius: 0.0 }.area(), 0.0);
+    }
+
+    #[test]
+    fn default_and_overridden_describe() {
+        assert_eq!(Circle { radius: 1.0 }.describe(), "This is a circle");
+        assert_eq!(
+            Square { side: 2.0 }.describe(),
+            "A square with four sides of 2"
+        );
+    }
+
+    #[test]
+    fn scene_total_area() {
+        let mut scene = Scene::new();
+        assert_eq!(scene.total_area(), 0.0);
+
+        scene.add(Box::new(Square { side: 2.0 }));
+        scene.add(Box::new(Triangle {
+            base: 2.0,
+            height: 2.0,
+        }));
+        assert_eq!(scene.total_area(), 6.0);
+    }
+}
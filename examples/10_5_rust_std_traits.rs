@@ -0,0 +1,269 @@
+// 🦀 Rust Standard Traits Exercises
+// Type out each exercise to build muscle memory!
+// Stuck on one? `cargo run -- hint 10_5` shows a hint; add `--level 2` or `--level 3` for more.
+// Follow-up to 10_3 and 10_4: instead of our own traits, implement the ones from std.
+// They're what make `{}`, `==`, `<`, `+`, `.sort()` and `.into()` work on your types.
+
+// The helpers below stay unused until you call them from main
+#![allow(dead_code)]
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::Add;
+
+// `#[derive]` writes the obvious implementation for you:
+// compare/copy/print every field, in order.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct User {
+    username: String,
+    email: String,
+    age: u32,
+}
+
+// File 11's Shape, not the library's (src/shapes.rs): this file implements Display and
+// Default for it, and a crate can only implement std's traits for its own types
+#[derive(Debug, Clone, PartialEq)]
+enum Shape {
+    Circle { radius: f64 },
+    Rectangle { width: f64, height: f64 },
+    Triangle { base: f64, height: f64 },
+}
+
+// Display is what `{}` uses. It's never derived: only you know how your type should read.
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl fmt::Display for User {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} <{}>, age {}", self.username, self.email, self.age)
+    }
+}
+
+impl fmt::Display for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Shape::Circle { radius } => write!(f, "circle r={}", radius),
+            Shape::Rectangle { width, height } => write!(f, "rectangle {}x{}", width, height),
+            Shape::Triangle { base, height } => write!(f, "triangle {}x{}", base, height),
+        }
+    }
+}
+
+// `a + b` is sugar for `a.add(b)`. `Output` says what the sum is.
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+// From gives us Into for free: `let p: Point = (1.0, 2.0).into();`
+impl From<(f64, f64)> for Point {
+    fn from((x, y): (f64, f64)) -> Point {
+        Point { x, y }
+    }
+}
+
+// A derived Default would give an empty username, so write it by hand
+impl Default for User {
+    fn default() -> User {
+        User {
+            username: String::from("Guest"),
+            email: String::from("guest@example.com"),
+            age: 0,
+        }
+    }
+}
+
+// Enums can derive Default only for a unit variant (`#[default] None`).
+// Ours all carry data, so pick one by hand.
+impl Default for Shape {
+    fn default() -> Shape {
+        Shape::Circle { radius: 1.0 }
+    }
+}
+
+// Ord is a total order: any two users compare as Less, Equal or Greater.
+// We order by age, then by username so users of the same age still have an order.
+// It must agree with the derived `==`, so the email breaks the last tie.
+impl Ord for User {
+    fn cmp(&self, other: &User) -> Ordering {
+        self.age
+            .cmp(&other.age)
+            .then_with(|| self.username.cmp(&other.username))
+            .then_with(|| self.email.cmp(&other.email))
+    }
+}
+
+// If you have Ord, PartialOrd should just agree with it
+impl PartialOrd for User {
+    fn partial_cmp(&self, other: &User) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Shape {
+    fn area(&self) -> f64 {
+        match self {
+            Shape::Circle { radius } => std::f64::consts::PI * radius * radius,
+            Shape::Rectangle { width, height } => width * height,
+            Shape::Triangle { base, height } => 0.5 * base * height,
+        }
+    }
+}
+
+fn main() {
+    println!("=== Exercise 1: Display vs Debug ===");
+    // TODO: Make a Point 'point' at (1.5, -2.0). Print it with `{}`, which uses our
+    // Display impl, and with `{:?}`, which uses the derived Debug.
+
+    // TODO: Display also gives you .to_string(). Make a User 'user' (Alice,
+    // alice@gmail.com, 29), turn it into a String and print that.
+
+    println!("\n=== Exercise 2: Default ===");
+    // TODO: Print the default Point, User and Shape. Derived Default sets every
+    // field to its own default (0.0 for f64).
+
+    // TODO: Struct update syntax: make 'bob', a User with username "Bob" and age 25,
+    // taking the rest from Default
+
+    println!("\n=== Exercise 3: PartialEq and Eq ===");
+    // TODO: == compares every field. Is point equal to a new Point (1.5, -2.0)?
+    // Is user equal to bob? Is the default Shape a circle of radius 1?
+
+    // TODO: Point can't derive Eq, because f64 isn't Eq: NaN != NaN (uncomment to see error)
+    // #[derive(PartialEq, Eq)]
+    // struct Bad { x: f64 } // Error: the trait bound `f64: Eq` is not satisfied
+
+    // TODO: That's why Point is only PartialEq: a point with NaN isn't equal to its own
+    // copy. Make one with x = f64::NAN, copy it, and compare the two.
+
+    println!("\n=== Exercise 4: PartialOrd and Ord ===");
+    // TODO: Derived PartialOrd compares x first, then y (like a dictionary).
+    // Make 'a' at (1.0, 9.0) and 'b' at (2.0, 0.0), and print whether a < b.
+
+    // TODO: Ord lets you call .sort(), .max() and friends. Put user, bob and a default
+    // User in a Vec, sort it and print each one.
+
+    // TODO: Points only have PartialOrd, so .sort() won't compile (uncomment to see error)
+    // let mut points = vec![b, a];
+    // points.sort(); // Error: the trait bound `Point: Ord` is not satisfied
+
+    println!("\n=== Exercise 5: Operator Overloading With Add ===");
+    // TODO: Add a + b and print the sum
+
+    println!("\n=== Exercise 6: From and Into ===");
+    // TODO: Build a point from (3.0, 4.0) with Point::from, and one from (5.0, 6.0) with into()
+
+    // TODO: Functions can accept anything that converts into a Point. Pass the tuple
+    // (3.0, 4.0) straight to distance_from_origin.
+
+    println!("\n=== Exercise 7: Sorting Shapes by Area ===");
+    // TODO: Shape doesn't implement PartialOrd. A 2x2 square and a 1x4 rectangle have the
+    // same area but aren't ==, and an ordering has to agree with ==. So sort by a key instead:
+    // put a 10x5 rectangle, the default Shape and a 6x12 triangle in a Vec, call
+    // sort_by_area, then print each area (2 decimals) next to its shape.
+
+    println!("\n✅ All exercises complete!");
+}
+
+// Helper function: `impl Into<Point>` accepts a Point, a (f64, f64), or anything else with From
+fn distance_from_origin(point: impl Into<Point>) -> f64 {
+    let point = point.into();
+    (point.x * point.x + point.y * point.y).sqrt()
+}
+
+// Helper function: total_cmp is a full ordering for f64 (it even puts NaN somewhere)
+fn sort_by_area(shapes: &mut [Shape]) {
+    shapes.sort_by(|a, b| a.area().total_cmp(&b.area()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(username: &str, age: u32) -> User {
+        User {
+            username: String::from(username),
+            age,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn display_formats() {
+        assert_eq!(Point { x: 1.0, y: -2.5 }.to_string(), "(1, -2.5)");
+        assert_eq!(
+            User::default().to_string(),
+            "Guest <guest@example.com>, age 0"
+        );
+        assert_eq!(Shape::default().to_string(), "circle r=1");
+    }
+
+    #[test]
+    fn points_add_and_convert() {
+        let sum = Point::from((1.0, 2.0)) + (3.0, 4.0).into();
+
+        assert_eq!(sum, Point { x: 4.0, y: 6.0 });
+        assert_eq!(Point::default() + sum, sum);
+        assert_eq!(distance_from_origin(Point { x: 3.0, y: 4.0 }), 5.0);
+    }
+
+    #[test]
+    fn points_compare_x_then_y() {
+        assert!(Point::from((1.0, 9.0)) < Point::from((2.0, 0.0)));
+        assert!(Point::from((1.0, 1.0)) < Point::from((1.0, 2.0)));
+
+        let nan = Point::from((f64::NAN, 0.0));
+        assert_ne!(nan, nan);
+        assert_eq!(nan.partial_cmp(&nan), None);
+    }
+
+    #[test]
+    fn users_sort_by_age_then_name() {
+        let mut users = [user("Carol", 30), user("Bob", 25), user("Alice", 30)];
+        users.sort();
+
+        let names: Vec<&str> = users.iter().map(|u| u.username.as_str()).collect();
+        assert_eq!(names, vec!["Bob", "Alice", "Carol"]);
+        assert_eq!(user("Alice", 30).cmp(&user("Alice", 30)), Ordering::Equal);
+    }
+
+    #[test]
+    fn shapes_sort_by_area() {
+        let mut shapes = vec![
+            Shape::Rectangle {
+                width: 1.0,
+                height: 4.0,
+            },
+            Shape::Triangle {
+                base: 1.0,
+                height: 1.0,
+            },
+            Shape::default(),
+        ];
+        sort_by_area(&mut shapes);
+
+        assert_eq!(
+            shapes[0],
+            Shape::Triangle {
+                base: 1.0,
+                height: 1.0
+            }
+        );
+        assert_eq!(shapes[1], Shape::default());
+    }
+}
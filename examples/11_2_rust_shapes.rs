@@ -0,0 +1,167 @@
+// 🦀 Rust Shapes Library Exercises
+// Type out each exercise to build muscle memory!
+// Stuck on one? `cargo run -- hint 11_2` shows a hint; add `--level 2` or `--level 3` for more.
+// Follow-up to 11_rust_enums.rs. Its Shape enum now lives in the library (src/shapes.rs)
+// with perimeter(), Display and FromStr, so here we use it: parse shapes from text,
+// then read a whole list of them from stdin and print a table.
+//
+//     printf 'circle 5\nsquare 3\ntriangle 6 4\n' | cargo run --example 11_2_rust_shapes
+//
+// Or run it without piping anything in and type the shapes, one per line. Press Ctrl-D
+// (Ctrl-Z then Enter on Windows) when you're done.
+// Run the tests with: cargo test --example 11_2_rust_shapes
+
+// Nothing calls the helpers (or uses every import) until you write main
+#![allow(dead_code, unused_imports)]
+
+use rust_tutorial::shapes::{ParseShapeError, Shape, total_area};
+use std::io::{self, BufRead};
+
+fn main() {
+    println!("=== Exercise 1: Parsing With FromStr ===");
+    // TODO: Shape implements FromStr, so str::parse can build one. It returns a Result.
+    // Parse "circle 5.0" and print the Result with {:?}.
+
+    // TODO: Every way a line can be wrong has its own error message. Parse "hexagon 2",
+    // "rectangle 4" and "circle five", printing either the shape or the error.
+
+    println!("\n=== Exercise 2: Area, Perimeter and Display ===");
+    // TODO: Display prints a shape the way you'd type it. Print each of sample_shapes()
+    // with its area and perimeter, to 2 decimals.
+
+    // TODO: ...so printing a shape and parsing it again gives back the same shape.
+    // Check that for the third sample.
+
+    println!("\n=== Exercise 3: A Free Function Over a Slice ===");
+    // TODO: total_area is about many shapes, not one, so it isn't a method.
+    // Print the total area of the samples.
+
+    println!("\n=== Exercise 4: Reading Lines From stdin ===");
+    // TODO: Read shapes with read_shapes until the input ends. Bad lines are reported,
+    // not fatal, so print each error as "Skipped ...".
+
+    // TODO: Nothing typed (or nothing piped in) still deserves a table, so fall back
+    // to the samples
+
+    println!("\n=== Exercise 5: A Summary Table ===");
+    // TODO: Print summary_table for the shapes. Read how it lines up the columns:
+    // {:<20} pads to 20 characters, left-aligned; {:>10.2} right-aligns 2 decimals.
+
+    println!("\n✅ All exercises complete!");
+}
+
+// Helper function: one of each kind of shape
+fn sample_shapes() -> Vec<Shape> {
+    vec![
+        Shape::Circle { radius: 5.0 },
+        Shape::square(3.0),
+        Shape::Triangle {
+            base: 6.0,
+            height: 4.0,
+        },
+    ]
+}
+
+// Helper function: blank lines and `#` comments are skipped. A line that doesn't parse
+// is reported with its line number, and the lines after it are still read.
+fn read_shapes(input: impl BufRead) -> (Vec<Shape>, Vec<String>) {
+    let mut shapes = Vec::new();
+    let mut errors = Vec::new();
+
+    for (index, line) in input.lines().enumerate() {
+        let line = match line {
+            Ok(line) => line,
+            Err(e) => {
+                errors.push(format!("line {}: {}", index + 1, e));
+                break;
+            }
+        };
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        match line.parse::<Shape>() {
+            Ok(shape) => shapes.push(shape),
+            Err(e) => errors.push(format!("line {}: {}", index + 1, e)),
+        }
+    }
+    (shapes, errors)
+}
+
+// Helper function: returns the table as a String rather than printing it, so the
+// tests can check it
+fn summary_table(shapes: &[Shape]) -> String {
+    let rule = "-".repeat(42);
+    let mut table = format!(
+        "{:<20} {:>10} {:>10}\n{}\n",
+        "shape", "area", "perimeter", rule
+    );
+
+    for shape in shapes {
+        table += &format!(
+            "{:<20} {:>10.2} {:>10.2}\n",
+            shape.to_string(),
+            shape.area(),
+            shape.perimeter()
+        );
+    }
+
+    let label = format!("total ({} shapes)", shapes.len());
+    table += &format!("{}\n{:<20} {:>10.2}\n", rule, label, total_area(shapes));
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_good_lines_and_reports_bad_ones() {
+        let input = "circle 1\n\n# a comment\nsquare two\n  rectangle 2 3  \nhexagon 1\n";
+        let (shapes, errors) = read_shapes(input.as_bytes());
+
+        assert_eq!(
+            shapes,
+            vec![
+                Shape::Circle { radius: 1.0 },
+                Shape::Rectangle {
+                    width: 2.0,
+                    height: 3.0
+                }
+            ]
+        );
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].starts_with("line 4: "));
+        assert!(errors[1].starts_with("line 6: unknown shape 'hexagon'"));
+    }
+
+    #[test]
+    fn empty_input_reads_nothing() {
+        let (shapes, errors) = read_shapes(io::empty());
+        assert!(shapes.is_empty());
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn table_has_a_row_per_shape_and_a_total() {
+        let table = summary_table(&[Shape::square(2.0), Shape::square(1.0)]);
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(lines.len(), 6);
+        assert!(lines[2].starts_with("rectangle 2 2"));
+        assert!(lines[2].ends_with("4.00       8.00"));
+        assert!(lines[5].starts_with("total (2 shapes)"));
+        assert!(lines[5].ends_with("5.00"));
+    }
+
+    #[test]
+    fn table_of_nothing() {
+        let table = summary_table(&[]);
+        assert_eq!(table.lines().count(), 4);
+        assert_eq!(
+            table.lines().last().unwrap().split_whitespace().last(),
+            Some("0.00")
+        );
+    }
+}
@@ -1,36 +1,20 @@
-enum Shape {
-    Circle { radius: f64 },
-    Rectangle { width: f64, height: f64 },
-    Triangle { base: f64, height: f64 },
-}
+// The Shape enum lives in the library now (src/shapes.rs), along with area, square,
+// perimeter and parsing. Open that file next to this one: its definition is the
+// struct-like enum you'd write here. See 11_2_rust_shapes.rs once you're done.
+use rust_tutorial::shapes::Shape;
 
-impl Shape {
-    fn area(&self) -> f64 {
-        match self {
-            Shape::Circle { radius } => 3.1415 * radius * radius,
-            Shape::Rectangle { width, height } => width * height,
-            Shape::Triangle { base, height } => 0.5 * base * height,
+// A free function, not a method: you can only add methods (`impl Shape`) in the crate
+// that defines the type, and that's the library
+fn describe(shape: &Shape) {
+    match shape {
+        Shape::Circle { radius } => {
+            println!("Circle with radius {}", radius);
         }
-    }
-
-    fn describe(&self) {
-        match self {
-            Shape::Circle { radius } => {
-                println!("Circle with radius {}", radius);
-            }
-            Shape::Rectangle { width, height } => {
-                println!("Rectangle {}x{}", width, height);
-            }
-            Shape::Triangle { base, height } => {
-                println!("Triangle with base {} and height {}", base, height);
-            }
+        Shape::Rectangle { width, height } => {
+            println!("Rectangle {}x{}", width, height);
         }
-    }
-
-    fn square(side: f64) -> Shape {
-        Shape::Rectangle {
-            width: (side),
-            height: (side),
+        Shape::Triangle { base, height } => {
+            println!("Triangle with base {} and height {}", base, height);
         }
     }
 }
@@ -47,15 +31,66 @@ fn main() {
         height: (12.),
     };
 
-    circle.describe();
+    describe(&circle);
     println!("Area: {}\n", circle.area());
 
-    rectangle.describe();
+    describe(&rectangle);
     println!("Area: {}\n", rectangle.area());
 
-    square.describe();
+    describe(&square);
     println!("Area: {}", square.area());
 
-    triangle.describe();
+    describe(&triangle);
     println!("Area: {}", triangle.area());
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn circle_area() {
+        let circle = Shape::Circle { radius: 2.0 };
+        assert!((circle.area() - 4.0 * std::f64::consts::PI).abs() < 1e-9);
+    }
+
+    #[test]
+    fn zero_sized_shapes_have_no_area() {
+        assert_eq!(Shape::Circle { radius: 0.0 }.area(), 0.0);
+        assert_eq!(Shape::square(0.0).area(), 0.0);
+        assert_eq!(
+            Shape::Triangle {
+                base: 0.0,
+                height: 9.0
+            }
+            .area(),
+            0.0
+        );
+    }
+
+    #[test]
+    fn rectangle_and_triangle_area() {
+        let rectangle = Shape::Rectangle {
+            width: 10.0,
+            height: 5.0,
+        };
+        let triangle = Shape::Triangle {
+            base: 6.0,
+            height: 12.0,
+        };
+
+        assert_eq!(rectangle.area(), 50.0);
+        assert_eq!(triangle.area(), 36.0);
+    }
+
+    #[test]
+    fn square_is_a_rectangle_with_equal_sides() {
+        match Shape::square(7.0) {
+            Shape::Rectangle { width, height } => {
+                assert_eq!(width, 7.0);
+                assert_eq!(height, 7.0);
+            }
+            _ => panic!("square() should build a Rectangle"),
+        }
+    }
+}
@@ -0,0 +1,216 @@
+// 🦀 Rust Generics: Bounds, where Clauses and impl Trait Exercises
+// Type out each exercise to build muscle memory!
+// Stuck on one? `cargo run -- hint 12_2` shows a hint; add `--level 2` or `--level 3` for more.
+// Follow-up to 12_rust_generics.rs. There, `largest<T: PartialOrd + Copy>` returned a
+// copy of the item, so it only handled Copy types. Here we fix that, and go further.
+
+// summarize, evens_up_to and the rest go unused until main calls them
+#![allow(dead_code)]
+
+use std::fmt::{Debug, Display};
+
+// No bounds on the struct itself: any T can live in a Pair.
+// Bounds go on the impl blocks that actually need them.
+#[derive(Debug)]
+struct Pair<T> {
+    first: T,
+    second: T,
+}
+
+// Available for every Pair<T>
+impl<T> Pair<T> {
+    fn new(first: T, second: T) -> Pair<T> {
+        Pair { first, second }
+    }
+
+    fn swap(self) -> Pair<T> {
+        Pair {
+            first: self.second,
+            second: self.first,
+        }
+    }
+}
+
+// Conditional methods: these only exist when T can be compared
+impl<T: PartialOrd> Pair<T> {
+    fn larger(&self) -> &T {
+        if self.first >= self.second {
+            &self.first
+        } else {
+            &self.second
+        }
+    }
+}
+
+// ...and this one when T can also be printed
+impl<T: PartialOrd + Display> Pair<T> {
+    fn announce_larger(&self) {
+        println!("The larger member is {}", self.larger());
+    }
+}
+
+// Two type parameters: the key and the value can be different types
+#[derive(Debug)]
+struct Labeled<L, V> {
+    label: L,
+    value: V,
+}
+
+impl<L, V> Labeled<L, V> {
+    // The types travel with the fields, so swapping gives a Labeled<V, L>
+    fn flip(self) -> Labeled<V, L> {
+        Labeled {
+            label: self.value,
+            value: self.label,
+        }
+    }
+}
+
+fn main() {
+    println!("=== Exercise 1: Generic Struct, Conditional Methods ===");
+    // TODO: Pairs of numbers and of Strings both get .larger(). Make a Pair of 3 and 7,
+    // and one of "apple" and "pear", and print the larger of each.
+
+    // TODO: announce_larger also needs Display, which i32 has. Swap the numbers, then
+    // announce the larger one.
+
+    // TODO: Vec<i32> implements PartialOrd but not Display (uncomment to see error)
+    // let lists = Pair::new(vec![1, 2], vec![1, 3]);
+    // println!("Larger list: {:?}", lists.larger()); // fine: PartialOrd is enough
+    // lists.announce_larger(); // Error: `Vec<i32>` doesn't implement `std::fmt::Display`
+
+    println!("\n=== Exercise 2: where Clauses ===");
+    // TODO: Once there are several bounds, move them into a `where` clause. Read
+    // summarize below, then call it on ['q', 'z', 'a'] labeled "letters", on
+    // [Some(1.5), None] labeled 2024, and on an empty Vec<i32>.
+
+    println!("\n=== Exercise 3: Multiple Type Parameters ===");
+    // TODO: Make a Labeled<&str, i32> with label "score" and value 99, print it,
+    // then flip it into a Labeled<i32, &str> and print that
+
+    println!("\n=== Exercise 4: Returning impl Iterator ===");
+    // TODO: The caller only knows "some iterator of u32", not the long adapter type.
+    // Collect evens_up_to(10) into a Vec and print it.
+
+    // TODO: The returned iterator can borrow from the arguments. Collect the scores
+    // 55, 92, 71 and 88 that are at_least 70.
+
+    println!("\n=== Exercise 5: largest Without Copy ===");
+    // TODO: Returning a reference means nothing is copied, so String works.
+    // Find the largest of the names "Ferris", "Corro" and "Bors".
+
+    // TODO: And an empty slice still returns None
+
+    println!("\n=== Exercise 6: Monomorphization ===");
+    // TODO: Read this, no typing needed. Generics cost nothing at runtime because the
+    // compiler copies a generic function once for every concrete type it's used with.
+    // The two calls below:
+    //
+    //     largest(&[1, 5, 3]);             // T = i32
+    //     largest(&names);                 // T = String
+    //
+    // compile as if we had written two ordinary functions:
+    //
+    //     fn largest_i32(list: &[i32]) -> Option<&i32> { ... }
+    //     fn largest_string(list: &[String]) -> Option<&String> { ... }
+    //
+    // That's why generic code is as fast as hand-written code, and why heavy use of
+    // generics makes binaries bigger. Trait objects (file 10_4) make the other trade-off:
+    // one copy of the function, with a vtable lookup at runtime.
+
+    // TODO: Make both calls and print what they find
+
+    println!("\n✅ All exercises complete!");
+}
+
+// Helper function: inline bounds `<T: Display, U: Debug + PartialOrd>` would work too,
+// but a where clause keeps the signature readable as the list grows
+fn summarize<T, U>(label: T, values: &[U]) -> String
+where
+    T: Display,
+    U: Debug + PartialOrd,
+{
+    match largest(values) {
+        Some(max) => format!("{}: {} values, largest {:?}", label, values.len(), max),
+        None => format!("{}: no values", label),
+    }
+}
+
+// Helper function: the real return type is Filter<RangeInclusive<u32>, closure>,
+// which you couldn't even write out (closures have no name)
+fn evens_up_to(limit: u32) -> impl Iterator<Item = u32> {
+    (0..=limit).filter(|n| n % 2 == 0)
+}
+
+// Helper function: the iterator borrows `items` and `min`, so it can't outlive them
+fn at_least<'a, T: PartialOrd>(items: &'a [T], min: &'a T) -> impl Iterator<Item = &'a T> {
+    items.iter().filter(move |item| *item >= min)
+}
+
+// Helper function: only PartialOrd is needed, since we compare and hand back references
+fn largest<T: PartialOrd>(list: &[T]) -> Option<&T> {
+    let mut largest = list.first()?;
+
+    for item in list {
+        if item > largest {
+            largest = item;
+        }
+    }
+    Some(largest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pair_larger_and_swap() {
+        let pair = Pair::new(String::from("b"), String::from("a"));
+        assert_eq!(pair.larger(), "b");
+
+        let swapped = pair.swap();
+        assert_eq!(swapped.first, "a");
+        assert_eq!(swapped.larger(), "b");
+
+        // Ties go to first
+        let tie = Pair::new(2, 2);
+        assert!(std::ptr::eq(tie.larger(), &tie.first));
+    }
+
+    #[test]
+    fn labeled_flip() {
+        let flipped = Labeled {
+            label: 'x',
+            value: 1.5,
+        }
+        .flip();
+        assert_eq!(flipped.label, 1.5);
+        assert_eq!(flipped.value, 'x');
+    }
+
+    #[test]
+    fn iterators() {
+        assert_eq!(evens_up_to(5).collect::<Vec<_>>(), vec![0, 2, 4]);
+        assert_eq!(evens_up_to(0).collect::<Vec<_>>(), vec![0]);
+        assert_eq!(at_least(&[1, 5, 3], &3).count(), 2);
+        assert_eq!(at_least(&["a", "c"], &"b").collect::<Vec<_>>(), vec![&"c"]);
+    }
+
+    #[test]
+    fn largest_by_reference() {
+        assert_eq!(largest(&[1, 5, 3]), Some(&5));
+        assert_eq!(largest::<i32>(&[]), None);
+        assert_eq!(
+            largest(&[String::from("b"), String::from("c"), String::from("a")]),
+            Some(&String::from("c"))
+        );
+        assert_eq!(largest(&[1.0, f64::NAN, 2.0]), Some(&2.0));
+    }
+
+    #[test]
+    fn summarize_uses_display_and_debug() {
+        assert_eq!(summarize("ids", &[1, 2]), "ids: 2 values, largest 2");
+        assert_eq!(summarize(7, &["x"]), "7: 1 values, largest \"x\"");
+        assert_eq!(summarize('e', &[(); 0]), "e: no values");
+    }
+}
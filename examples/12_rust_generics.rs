@@ -1,20 +1,56 @@
-fn largest<T: PartialOrd + Copy>(list: &[T]) -> T {
-    let mut largest = list[0];
+// Returns None for an empty slice, which has no largest item
+fn largest<T: PartialOrd + Copy>(list: &[T]) -> Option<T> {
+    let mut largest = *list.first()?;
 
     for &item in list.iter() {
         if item > largest {
             largest = item;
         }
     }
-    largest
+    Some(largest)
 }
 
 fn main() {
     let nums = vec![34, 50, 25, 100, 65];
-    let result = largest(&nums);
-
-    println!("Largest = {} ", result);
+    match largest(&nums) {
+        Some(result) => println!("Largest number = {}", result),
+        None => println!("No numbers"),
+    }
 
     let chars = vec!['y', 'm', 'a', 'q'];
-    println!("largest = {}", largest(&chars));
+    match largest(&chars) {
+        Some(result) => println!("Largest char = {}", result),
+        None => println!("No chars"),
+    }
+
+    let empty: Vec<i32> = Vec::new();
+    match largest(&empty) {
+        Some(result) => println!("Largest of nothing = {}", result),
+        None => println!("An empty list has no largest item"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn largest_number_and_char() {
+        assert_eq!(largest(&[34, 50, 25, 100, 65]), Some(100));
+        assert_eq!(largest(&['y', 'm', 'a', 'q']), Some('y'));
+    }
+
+    #[test]
+    fn largest_edge_cases() {
+        assert_eq!(largest(&[42]), Some(42));
+        assert_eq!(largest(&[-5, -2, -9]), Some(-2));
+        assert_eq!(largest(&[3, 3, 3]), Some(3));
+        assert_eq!(largest(&[1.5, 0.5]), Some(1.5));
+    }
+
+    #[test]
+    fn largest_of_empty_slice_is_none() {
+        let empty: [i32; 0] = [];
+        assert_eq!(largest(&empty), None);
+    }
 }
@@ -0,0 +1,158 @@
+// 🦀 Rust Lifetimes Exercises
+// Type out each exercise to build muscle memory!
+// Stuck on one? `cargo run -- hint 14` shows a hint; add `--level 2` or `--level 3` for more.
+// Lifetimes pick up where ownership and borrowing (file 13) left off:
+// they tell the compiler how long a reference is allowed to live.
+
+// Until main calls them, the helpers below are dead code
+#![allow(dead_code)]
+
+fn main() {
+    println!("=== Exercise 1: Why Lifetimes Exist ===");
+    // TODO: Create a reference 'r' that outlives the value it points to (uncomment to see error)
+    // let r;
+    // {
+    //     let x = 5;
+    //     r = &x;
+    // } // Error: `x` does not live long enough
+    // println!("r = {}", r);
+
+    // TODO: Fix it by making x live as long as r: declare x = 5 and r = &x in the
+    // same scope, then print r
+
+    println!("\n=== Exercise 2: Lifetime Annotations on Functions ===");
+    // TODO: Create two Strings, "long string is long" and "xyz", call longest with
+    // slices of both and print the result
+
+    println!("\n=== Exercise 3: The Result Lives As Long As the Shorter Input ===");
+    // TODO: Create 'outer' ("outer lives longer") here, and 'inner' ("inner") inside a
+    // { } block. Call longest with both and print the result inside the block too.
+
+    // TODO: Try to use the result after 'inner' is dropped (uncomment to see error)
+    // let result;
+    // {
+    //     let inner = String::from("inner");
+    //     result = longest(outer.as_str(), inner.as_str());
+    // } // Error: `inner` does not live long enough
+    // println!("Outside the scope: {}", result);
+
+    println!("\n=== Exercise 4: Only Annotate What You Return ===");
+    // TODO: Call first_of, whose result only borrows from the first argument. Pass it
+    // outer and a String made inside a { } block, then print the result after the block.
+
+    println!("\n=== Exercise 5: Elision Rules ===");
+    // TODO: Call first_word on "lifetimes are elided here" (no annotations needed,
+    // the compiler fills them in)
+
+    println!("\n=== Exercise 6: Structs Holding References ===");
+    // TODO: Create a novel, "Call me Ishmael. Some years ago...", and an Excerpt that
+    // borrows its first sentence (split on '.'). Print the excerpt's part.
+
+    // TODO: Call the methods on Excerpt: level, and announce_and_return_part with
+    // "here comes an excerpt"
+
+    // TODO: Try to keep the Excerpt after its text is dropped (uncomment to see error)
+    // let excerpt;
+    // {
+    //     let text = String::from("Short lived. Gone soon.");
+    //     excerpt = Excerpt { part: &text };
+    // } // Error: `text` does not live long enough
+    // println!("{}", excerpt.part);
+
+    println!("\n=== Exercise 7: The 'static Lifetime ===");
+    // TODO: String literals live for the whole program. Store one in a &'static str
+    // and print it.
+
+    // TODO: A function can hand out 'static references safely. Print greeting().
+
+    // TODO: A String is NOT 'static, borrowing it gives a shorter lifetime (uncomment to see error)
+    // let owned = String::from("not static");
+    // let s: &'static str = &owned; // Error: `owned` does not live long enough
+
+    println!("\n=== Exercise 8: Fix the Broken Function ===");
+    // TODO: Uncomment broken_longest below and read the error:
+    //       "missing lifetime specifier"
+    //       The compiler can't tell if the result borrows from x or from y.
+    // TODO: Fix it by adding <'a> like longest, then call it with "apple" and "banana"
+
+    println!("\n✅ All exercises complete!");
+}
+
+// Helper function: Returns the longer of two string slices
+// 'a means: the result lives as long as the SHORTER of x and y
+fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+    if x.len() > y.len() { x } else { y }
+}
+
+// Helper function: Result is tied only to x, so y can have any lifetime
+fn first_of<'a>(x: &'a str, _y: &str) -> &'a str {
+    x
+}
+
+// Helper function: One input reference, so elision gives the output the same lifetime
+// Same as: fn first_word<'a>(s: &'a str) -> &'a str
+fn first_word(s: &str) -> &str {
+    s.split_whitespace().next().unwrap_or("")
+}
+
+// Helper function: Returns a reference that is valid for the whole program
+fn greeting() -> &'static str {
+    "Hello from the binary's read-only data!"
+}
+
+// Struct holding a reference: an Excerpt can't outlive the text it borrows
+struct Excerpt<'a> {
+    part: &'a str,
+}
+
+impl<'a> Excerpt<'a> {
+    // Elision rule: no references returned, no annotations needed
+    fn level(&self) -> i32 {
+        3
+    }
+
+    // With &self, elision would tie the output to the Excerpt itself.
+    // Writing &'a says it borrows the original text instead, which lives longer.
+    fn announce_and_return_part(&self, announcement: &str) -> &'a str {
+        println!("Attention please: {}", announcement);
+        self.part
+    }
+}
+
+// Broken function: deliberately missing lifetime annotations
+// TODO: Uncomment to see the error, then fix it
+// fn broken_longest(x: &str, y: &str) -> &str {
+//     if x.len() > y.len() { x } else { y }
+// }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn longest_picks_the_longer_slice() {
+        assert_eq!(longest("long string is long", "xyz"), "long string is long");
+        assert_eq!(longest("ab", "abc"), "abc");
+        // Ties go to the second argument
+        assert_eq!(longest("one", "two"), "two");
+        assert_eq!(longest("", ""), "");
+    }
+
+    #[test]
+    fn first_word_handles_edge_cases() {
+        assert_eq!(first_word("hello world"), "hello");
+        assert_eq!(first_word("   padded"), "padded");
+        assert_eq!(first_word(""), "");
+    }
+
+    #[test]
+    fn excerpt_returns_the_borrowed_part() {
+        let text = String::from("First. Second.");
+        let excerpt = Excerpt {
+            part: text.split('.').next().unwrap(),
+        };
+
+        assert_eq!(excerpt.announce_and_return_part("test"), "First");
+        assert_eq!(excerpt.level(), 3);
+    }
+}
@@ -0,0 +1,222 @@
+// 🦀 Rust Error Handling Exercises
+// Type out each exercise to build muscle memory!
+// Stuck on one? `cargo run -- hint 15` shows a hint; add `--level 2` or `--level 3` for more.
+// We build a tiny parser for a scores file that looks like:
+//
+//     alice, 90
+//     bob, 75
+//
+// Every step that can fail returns a Result instead of panicking.
+// Run the tests with: cargo test --example 15_rust_error_handling
+
+// main starts out empty, so the helpers and some imports go unused until you fill it in
+#![allow(dead_code, unused_imports)]
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::num::ParseIntError;
+use std::path::Path;
+
+// Custom error enum: one variant per way parsing can go wrong
+#[derive(Debug)]
+enum ParseError {
+    Io(io::Error),                               // couldn't read the file
+    MissingScore { line: usize },                // "alice" with no ", 90"
+    InvalidScore { line: usize, value: String }, // "alice, ninety"
+    Empty,                                       // nothing to parse
+}
+
+// Display: the human-readable message
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::Io(err) => write!(f, "could not read file: {}", err),
+            ParseError::MissingScore { line } => write!(f, "line {}: missing score", line),
+            ParseError::InvalidScore { line, value } => {
+                write!(f, "line {}: '{}' is not a valid score", line, value)
+            }
+            ParseError::Empty => write!(f, "file has no scores"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// From: lets `?` turn an io::Error into a ParseError automatically
+impl From<io::Error> for ParseError {
+    fn from(err: io::Error) -> Self {
+        ParseError::Io(err)
+    }
+}
+
+fn main() {
+    println!("=== Exercise 1: Result Is Just an Enum ===");
+    // TODO: Parse "42" into a Result<i32, ParseIntError> and match on both variants,
+    // printing "Parsed: ..." or "Failed: ..."
+
+    // TODO: Now do the same with a string that isn't a number, "forty-two"
+
+    println!("\n=== Exercise 2: Propagating With ? ===");
+    // TODO: Call double_number, which uses ? instead of match, on "21" and on "abc".
+    // Print both Results with {:?}.
+
+    println!("\n=== Exercise 3: A Custom Error Enum ===");
+    // TODO: Parse the lines "alice, 90", "bob" and "carol, lots" with parse_line,
+    // printing each name and score, or the error message
+
+    println!("\n=== Exercise 4: Reading a File (From<io::Error> + ?) ===");
+    // TODO: Write "alice, 90\nbob, 75\n" to rust_tutorial_scores.txt in
+    // std::env::temp_dir(), then parse it with read_scores and print the scores or error
+
+    // TODO: A missing file becomes ParseError::Io thanks to From. Read
+    // "does_not_exist.txt" and match on Err(ParseError::Io(e)) to print e.kind().
+
+    println!("\n=== Exercise 5: unwrap_or_else for Fallbacks ===");
+    // TODO: Read the missing file again, but fall back to an empty list when reading
+    // fails. Say why, then print how many scores you got.
+
+    println!("\n=== Exercise 6: Working With the Ok Value ===");
+    // TODO: Read the temp file again and chain average onto the Result with and_then.
+    // Print the average, or explain why we can't.
+
+    // TODO: unwrap() would panic on Err (uncomment to see the panic at runtime)
+    // let scores = read_scores(Path::new("does_not_exist.txt")).unwrap();
+
+    // TODO: Remove the temp file. Ignore the Result: it's fine if it's already gone.
+
+    println!("\n✅ All exercises complete!");
+}
+
+// Helper function: ? returns early with the error, or unwraps the Ok value
+fn double_number(text: &str) -> Result<i32, ParseIntError> {
+    let n: i32 = text.trim().parse()?;
+    Ok(n * 2)
+}
+
+// Helper function: "name, score" -> (name, score)
+// line_number is only used to make error messages helpful
+fn parse_line(line_number: usize, line: &str) -> Result<(String, u32), ParseError> {
+    let (name, score) = line
+        .split_once(',')
+        .ok_or(ParseError::MissingScore { line: line_number })?;
+
+    let score = score.trim();
+    let score: u32 = score.parse().map_err(|_| ParseError::InvalidScore {
+        line: line_number,
+        value: score.to_string(),
+    })?;
+
+    Ok((name.trim().to_string(), score))
+}
+
+// Helper function: Parse every non-blank line of some text
+fn parse_scores(text: &str) -> Result<Vec<(String, u32)>, ParseError> {
+    let mut scores = Vec::new();
+
+    for (index, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        scores.push(parse_line(index + 1, line)?);
+    }
+    Ok(scores)
+}
+
+// Helper function: The ? on read_to_string converts io::Error via From
+fn read_scores(path: &Path) -> Result<Vec<(String, u32)>, ParseError> {
+    let text = fs::read_to_string(path)?;
+    parse_scores(&text)
+}
+
+// Helper function: An average of nothing is an error, not a division by zero
+fn average(scores: &[(String, u32)]) -> Result<f64, ParseError> {
+    if scores.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    // Summed as u64: a few scores near u32::MAX would overflow a u32 total
+    let total: u64 = scores.iter().map(|(_, score)| u64::from(*score)).sum();
+    Ok(total as f64 / scores.len() as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn double_number_ok_and_err() {
+        assert_eq!(double_number("21"), Ok(42));
+        assert_eq!(double_number(" 5 "), Ok(10));
+        assert!(double_number("abc").is_err());
+        assert!(double_number("").is_err());
+    }
+
+    #[test]
+    fn parse_line_valid() {
+        let (name, score) = parse_line(1, "  alice ,  90 ").unwrap();
+        assert_eq!(name, "alice");
+        assert_eq!(score, 90);
+    }
+
+    #[test]
+    fn parse_line_missing_score() {
+        assert!(matches!(
+            parse_line(3, "bob"),
+            Err(ParseError::MissingScore { line: 3 })
+        ));
+    }
+
+    #[test]
+    fn parse_line_invalid_score() {
+        match parse_line(2, "carol, -5") {
+            Err(ParseError::InvalidScore { line, value }) => {
+                assert_eq!(line, 2);
+                assert_eq!(value, "-5");
+            }
+            other => panic!("expected InvalidScore, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_scores_skips_blank_lines_and_reports_real_line_numbers() {
+        let scores = parse_scores("alice, 90\n\nbob, 75\n").unwrap();
+        assert_eq!(scores.len(), 2);
+
+        assert!(matches!(
+            parse_scores("alice, 90\n\nbob\n"),
+            Err(ParseError::MissingScore { line: 3 })
+        ));
+    }
+
+    #[test]
+    fn missing_file_is_an_io_error() {
+        let err = read_scores(Path::new("definitely/not/here.txt")).unwrap_err();
+        match err {
+            ParseError::Io(e) => assert_eq!(e.kind(), io::ErrorKind::NotFound),
+            other => panic!("expected Io, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn average_of_nothing_is_an_error() {
+        assert!(matches!(average(&[]), Err(ParseError::Empty)));
+
+        let scores = vec![(String::from("a"), 80), (String::from("b"), 90)];
+        assert_eq!(average(&scores).unwrap(), 85.0);
+    }
+
+    #[test]
+    fn average_of_huge_scores_does_not_overflow() {
+        let scores = parse_scores("x, 4000000000\ny, 4000000000").unwrap();
+        assert_eq!(average(&scores).unwrap(), 4_000_000_000.0);
+    }
+
+    #[test]
+    fn error_messages() {
+        assert_eq!(
+            ParseError::MissingScore { line: 4 }.to_string(),
+            "line 4: missing score"
+        );
+        assert_eq!(ParseError::Empty.to_string(), "file has no scores");
+    }
+}
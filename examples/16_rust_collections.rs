@@ -0,0 +1,130 @@
+// 🦀 Rust Collections Exercises
+// Type out each exercise to build muscle memory!
+// Stuck on one? `cargo run -- hint 16` shows a hint; add `--level 2` or `--level 3` for more.
+// Vec, HashMap, HashSet and BTreeMap all live on the heap and own their items,
+// so everything from the ownership chapter (file 13) applies to them too.
+
+// text, the imports and the helpers all wait for you to use them in main
+#![allow(dead_code, unused_imports, unused_variables)]
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+fn main() {
+    let text = "the quick brown fox jumps over the lazy dog the end";
+
+    println!("=== Exercise 1: Vec Basics ===");
+    // TODO: Create an empty Vec<i32> called 'numbers' and push 10, 20 and 30
+
+    // TODO: Index directly (panics if out of bounds) vs .get() (returns Option).
+    // Print the first number with [0], and the tenth with .get(9).
+
+    // TODO: Iterate by reference to print each number, then by mutable reference to
+    // add 1 to each. Print the Vec afterwards.
+
+    println!("\n=== Exercise 2: Word Frequency With HashMap ===");
+    // TODO: Count each word of text into a HashMap<&str, i32> called 'counts', with
+    // insert + get (the long way)
+
+    // TODO: Look up a few words: "the" with [ ], and "cat" (missing) with .get()
+
+    println!("\n=== Exercise 3: The Entry API ===");
+    // TODO: Count again, the idiomatic way (read word_frequency at the bottom)
+
+    // TODO: HashMap iteration order is random! Collect the pairs into a Vec and sort
+    // it before printing
+
+    // TODO: Group the words by first letter in a HashMap<char, Vec<&str>>.
+    // or_default() inserts an empty Vec only when the key is missing.
+    // Print the words starting with 't'.
+
+    println!("\n=== Exercise 4: Deduplication With HashSet ===");
+    // TODO: Collect the words into a HashSet to drop duplicates. Print how many words
+    // there are, and how many are unique.
+
+    // TODO: insert returns false if the value was already there. Insert "fox" twice
+    // and print both results.
+
+    // TODO: Dedup while keeping the original order, with dedup_in_order
+
+    // TODO: Set operations: make sets of 1, 2, 3, 4 and of 3, 4, 5. Print the numbers
+    // in both, sorted.
+
+    println!("\n=== Exercise 5: Sorted Iteration With BTreeMap ===");
+    // TODO: Same counting code, but BTreeMap keeps keys sorted. Count into 'sorted'
+    // and print every word and count, no sorting needed.
+
+    // TODO: Sorted keys make ranges possible. Collect the words from "a" up to "m".
+
+    println!("\n=== Exercise 6: Ownership and Collections ===");
+    // TODO: Inserting a String moves it into the map. Insert the key "color" with the
+    // value "blue" into a HashMap<String, String> and print the map.
+    // Then try printing key on its own to see the error.
+
+    // TODO: remove hands ownership back to you. Remove "color" and print what you get.
+
+    println!("\n=== Exercise 7: Can't Mutate While Iterating ===");
+    // TODO: Try to push into a Vec while looping over it (uncomment to see error)
+    // let mut v = vec![1, 2, 3];
+    // for n in &v {
+    //     v.push(*n * 2); // Error: cannot borrow `v` as mutable because it is also borrowed as immutable
+    // }
+    // Why? push may reallocate, which would leave the loop's reference dangling.
+
+    // TODO: Same problem with a HashMap (uncomment to see error)
+    // let mut scores: HashMap<&str, i32> = HashMap::new();
+    // scores.insert("a", 1);
+    // for (name, _) in &scores {
+    //     scores.remove(name); // Error: cannot borrow `scores` as mutable
+    // }
+
+    // TODO: Fix 1: collect what you need first, then mutate. Collect the doubles of
+    // 1, 2, 3 into a new Vec, then extend the original with it.
+
+    // TODO: Fix 2: use a method that does the looping for you. retain the scores above
+    // 3 in a map of a: 1, b: 5, c: 9, then print the keys that are left, sorted.
+
+    println!("\n✅ All exercises complete!");
+}
+
+// Helper function: Count words with the entry API
+fn word_frequency(text: &str) -> HashMap<&str, i32> {
+    let mut counts = HashMap::new();
+    for word in text.split_whitespace() {
+        *counts.entry(word).or_insert(0) += 1;
+    }
+    counts
+}
+
+// Helper function: Drop repeated words but keep the first occurrence in place
+fn dedup_in_order(text: &str) -> Vec<&str> {
+    let mut seen = HashSet::new();
+    text.split_whitespace()
+        .filter(|word| seen.insert(*word))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn word_frequency_counts_repeats() {
+        let counts = word_frequency("a b a c a");
+
+        assert_eq!(counts["a"], 3);
+        assert_eq!(counts["b"], 1);
+        assert_eq!(counts.get("z"), None);
+    }
+
+    #[test]
+    fn word_frequency_of_nothing() {
+        assert!(word_frequency("").is_empty());
+        assert!(word_frequency("   ").is_empty());
+    }
+
+    #[test]
+    fn dedup_keeps_first_occurrence_order() {
+        assert_eq!(dedup_in_order("b a b c a"), vec!["b", "a", "c"]);
+        assert!(dedup_in_order("").is_empty());
+    }
+}
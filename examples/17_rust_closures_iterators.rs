@@ -0,0 +1,167 @@
+// 🦀 Rust Closures and Iterators Exercises
+// Type out each exercise to build muscle memory!
+// Stuck on one? `cargo run -- hint 17` shows a hint; add `--level 2` or `--level 3` for more.
+// Closures are functions that can capture variables from where they are defined.
+// Iterators are anything with a `next()` method, and closures drive most of their adapters.
+
+// With main empty, the helpers, Stack and the numbers in exercise 6 all go unused
+#![allow(dead_code, unused_imports, unused_variables)]
+
+use rust_tutorial::collections::Stack;
+
+fn main() {
+    println!("=== Exercise 1: Closure Syntax ===");
+    // TODO: Write the same add-one function three ways: as a nested fn, as a closure
+    // with its types written out, and as a closure that lets the compiler infer them.
+    // Call all three with 1.
+
+    println!("\n=== Exercise 2: Fn (Borrows Immutably) ===");
+    // TODO: Make a String 'greeting' ("Hello") and a closure that prints it with a name.
+    // Pass the closure to call_twice, then show that greeting is still yours.
+
+    println!("\n=== Exercise 3: FnMut (Borrows Mutably) ===");
+    // TODO: A closure that changes a captured variable must be `mut`. Write one that
+    // adds 1 to a count, call it twice and print the count.
+
+    // TODO: Pass a closure that adds 10 to a total to repeat, which takes FnMut.
+    // Run it 3 times.
+
+    println!("\n=== Exercise 4: FnOnce (Consumes What It Captured) ===");
+    // TODO: Write a closure that returns a captured String 'name' ("Ferris"). That gives
+    // name away, so it can only run once: hand it to consume and print what comes back.
+
+    println!("\n=== Exercise 5: Capturing With move ===");
+    // TODO: move forces the closure to take ownership, even though it only reads.
+    // Move a vec![1, 2, 3] into a closure that prints it, then call it.
+
+    // TODO: Returning a closure needs move so it doesn't borrow a dead local.
+    // Get an add-five closure from make_adder and call it with 10.
+
+    println!("\n=== Exercise 6: Iterator Adapters ===");
+    let numbers = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+    // TODO: map transforms every item. Collect the square of each number.
+
+    // TODO: filter keeps items where the closure returns true. Collect the even numbers.
+
+    // TODO: fold carries an accumulator through every item (here: a running sum and count)
+
+    // TODO: Chain them: the sum of the squares of the odd numbers
+
+    // TODO: Adapters are lazy, nothing runs until something consumes them. Write a map
+    // that prints each number it doubles, print "Nothing printed yet...", and only then
+    // take(2) of it.
+
+    // TODO: collect can build more than Vecs. Uppercase the words "iter", "map" and
+    // "fold", and join them with "-".
+
+    println!("\n=== Exercise 7: Writing Your Own Iterator ===");
+    // TODO: Read the Iterator impl for Countdown at the bottom of the file, then loop over
+    // a countdown from 3 and print "Liftoff!"
+
+    // TODO: Every adapter works on it for free, because we implemented next().
+    // Collect a countdown from 5, doubled.
+
+    println!("\n=== Exercise 8: Capstone - Iterating the Crate's Stack<T> ===");
+    // The library's Stack<T> (src/collections/stack.rs) has two hand-rolled iterators:
+    //   - stack.iter()      -> Iter<'_, T>: walks a slice backwards, yielding &T
+    //   - stack.into_iter() -> IntoIter<T>: owns the stack and pops until empty, yielding T
+    // Read their `next()` methods, they are only a few lines each.
+
+    // TODO: Build a Stack of three plates: "blue plate", "red plate" and "green plate"
+
+    // TODO: Borrow with iter() (or `for plate in &plates`) to print each plate. The
+    // stack is untouched, so print how many plates are left.
+
+    // TODO: Adapters work on our iterator just like on Vec's. Collect the plates with
+    // names longer than 9 bytes.
+
+    // TODO: into_iter() consumes the stack and hands out owned Strings, top first.
+    // Loop over the plates by value and "wash" each one.
+
+    println!("\n✅ All exercises complete!");
+}
+
+// Helper function: Accepts any closure that can be called with a &str many times
+fn call_twice<F: Fn(&str)>(f: F) {
+    f("Alice");
+    f("Bob");
+}
+
+// Helper function: FnMut because the closure is allowed to change what it captured
+fn repeat<F: FnMut()>(times: u32, mut f: F) {
+    for _ in 0..times {
+        f();
+    }
+}
+
+// Helper function: FnOnce is the most permissive bound, but we may only call it once
+fn consume<F: FnOnce() -> String>(f: F) -> String {
+    f()
+}
+
+// Helper function: Returns a closure that owns its copy of 'amount'
+fn make_adder(amount: i32) -> impl Fn(i32) -> i32 {
+    move |x| x + amount
+}
+
+// A custom iterator: counts down from a number to 1
+struct Countdown {
+    current: u32,
+}
+
+impl Countdown {
+    fn new(start: u32) -> Countdown {
+        Countdown { current: start }
+    }
+}
+
+// Implementing Iterator only takes next(); map, filter, collect etc. come for free
+impl Iterator for Countdown {
+    type Item = u32; // What next() hands out
+
+    fn next(&mut self) -> Option<u32> {
+        if self.current == 0 {
+            None // Returning None ends the iteration
+        } else {
+            self.current -= 1;
+            Some(self.current + 1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn countdown_yields_start_to_one() {
+        assert_eq!(Countdown::new(3).collect::<Vec<_>>(), vec![3, 2, 1]);
+        assert_eq!(Countdown::new(0).next(), None);
+    }
+
+    #[test]
+    fn make_adder_captures_its_amount() {
+        let add_two = make_adder(2);
+        let add_minus_one = make_adder(-1);
+
+        assert_eq!(add_two(40), 42);
+        assert_eq!(add_minus_one(0), -1);
+    }
+
+    #[test]
+    fn repeat_calls_the_closure_n_times() {
+        let mut calls = 0;
+        repeat(4, || calls += 1);
+        assert_eq!(calls, 4);
+
+        repeat(0, || calls += 1);
+        assert_eq!(calls, 4);
+    }
+
+    #[test]
+    fn consume_returns_what_the_closure_gave_away() {
+        let name = String::from("Ferris");
+        assert_eq!(consume(|| name), "Ferris");
+    }
+}
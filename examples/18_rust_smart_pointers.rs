@@ -0,0 +1,193 @@
+// 🦀 Rust Smart Pointers Exercises
+// Type out each exercise to build muscle memory!
+// Stuck on one? `cargo run -- hint 18` shows a hint; add `--level 2` or `--level 3` for more.
+// A smart pointer is a struct that acts like a reference (Deref) and
+// cleans up after itself (Drop). String and Vec are smart pointers too!
+
+// main starts empty, so none of the types below are used until you write the exercises
+#![allow(dead_code, unused_imports)]
+
+use std::cell::RefCell;
+use std::ops::Deref;
+use std::rc::{Rc, Weak};
+
+// Cons list: each element holds a value and the rest of the list.
+// Without Box this type would be infinitely large: List contains List contains List...
+// Box has a fixed size (one pointer), so the compiler knows how big a List is.
+#[derive(Debug)]
+enum List {
+    Cons(i32, Box<List>),
+    Nil,
+}
+
+use List::{Cons, Nil};
+
+// Our own Box-like pointer, to see what Deref and Drop actually do
+struct MyBox<T: std::fmt::Debug> {
+    value: T,
+}
+
+impl<T: std::fmt::Debug> MyBox<T> {
+    fn new(value: T) -> MyBox<T> {
+        MyBox { value }
+    }
+}
+
+// Deref: lets `*my_box` and auto-deref (`my_box.len()`) reach the inner value
+impl<T: std::fmt::Debug> Deref for MyBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+// Drop: runs automatically when the value goes out of scope
+impl<T: std::fmt::Debug> Drop for MyBox<T> {
+    fn drop(&mut self) {
+        println!("Dropping MyBox({:?})", self.value);
+    }
+}
+
+// A graph node that several owners can share and mutate
+#[derive(Debug)]
+struct Node {
+    name: String,
+    neighbors: RefCell<Vec<Rc<Node>>>,
+}
+
+// A tree node: parents own their children (Rc), children only point back (Weak)
+#[derive(Debug)]
+struct TreeNode {
+    value: i32,
+    parent: RefCell<Weak<TreeNode>>,
+    children: RefCell<Vec<Rc<TreeNode>>>,
+}
+
+// Used to demonstrate a reference cycle that never gets freed
+struct Leaky {
+    name: &'static str,
+    other: RefCell<Option<Rc<Leaky>>>,
+}
+
+impl Drop for Leaky {
+    fn drop(&mut self) {
+        println!("Dropping Leaky {}", self.name);
+    }
+}
+
+fn main() {
+    println!("=== Exercise 1: Box<T> Puts a Value on the Heap ===");
+    // TODO: Box the number 5, then print it and *b + 1. A Box works like the value inside.
+
+    println!("\n=== Exercise 2: A Cons List With Box ===");
+    // TODO: Try the recursive type without Box (uncomment to see error)
+    // enum BrokenList {
+    //     Cons(i32, BrokenList),
+    //     Nil,
+    // } // Error: recursive type `BrokenList` has infinite size
+
+    // TODO: Build the list 1 -> 2 -> 3 -> Nil out of the List above. Print it, and
+    // its sum from sum_list.
+
+    println!("\n=== Exercise 3: Deref on MyBox<T> ===");
+    // TODO: Put 5 in a MyBox and dereference it with * just like a Box
+
+    // TODO: Deref coercion: &MyBox<String> -> &String -> &str, all automatic.
+    // Pass a &MyBox<String> holding "Rust" to hello, which takes a &str.
+
+    println!("\n=== Exercise 4: Drop on MyBox<T> ===");
+    // TODO: Watch the drop order (reverse of creation): make two MyBoxes, "first" and
+    // "second", inside a { } block and print "Leaving the scope..." at its end
+
+    // TODO: Drop a MyBox 'c' early with std::mem::drop (calling c.drop() yourself is
+    // not allowed), then print that it's already gone
+
+    println!("\n=== Exercise 5: Rc<T> for Shared Ownership ===");
+    // TODO: Clone the Rc (cheap: only bumps a counter) and watch the count. Put
+    // "shared data" in an Rc, clone it into 'a', and into 'b' inside a { } block.
+    // Print Rc::strong_count after new, while both clones exist, and after b is dropped.
+
+    println!("\n=== Exercise 6: Rc<RefCell<T>> for Shared AND Mutable ===");
+    // TODO: Rc alone only gives & access; RefCell moves the borrow check to runtime.
+    // Share an Rc<RefCell<i32>> 'score' between two players. One adds 10 through
+    // borrow_mut(), the other adds 5, then print the score.
+
+    // TODO: Two borrow_mut() at once compiles, but panics at runtime (uncomment to see)
+    // let first = score.borrow_mut();
+    // let second = score.borrow_mut(); // Panic: already borrowed: BorrowMutError
+
+    // TODO: A small graph where one node is shared by two others. Make node C in an Rc,
+    // then nodes A and B that both list C as a neighbor: A when it's created, B by
+    // pushing onto its RefCell afterwards. Print each one's neighbors and C's owner count.
+
+    println!("\n=== Exercise 7: A Reference Cycle Leaks Memory ===");
+    // TODO: Inside a { } block, make two Rc<Leaky> values, x and y, that point at each
+    // other. Print both strong counts, then "(nothing was dropped)" after the block.
+    // Notice: no "Dropping Leaky" lines! Each count only falls from 2 to 1,
+    // because x keeps y alive and y keeps x alive. That memory is never freed.
+
+    println!("\n=== Exercise 8: Breaking the Cycle With Weak<T> ===");
+    // TODO: The parent owns the child with Rc; the child points back with Weak.
+    // Make a leaf TreeNode (value 3) with no parent yet, and print its parent's value.
+
+    // TODO: Inside a { } block, create a branch (value 5) that owns the leaf, then point
+    // the leaf back at it with Rc::downgrade. Print the leaf's parent again, and the
+    // branch's strong count, weak count and number of children.
+
+    // TODO: After the block, the branch was freed: a Weak doesn't keep it alive, so
+    // upgrade() gives None. Print the leaf's parent and strong count one last time.
+
+    println!("\n✅ All exercises complete!");
+}
+
+// Helper function: Walk the list recursively, following each Box
+fn sum_list(list: &List) -> i32 {
+    match list {
+        Cons(value, rest) => value + sum_list(rest),
+        Nil => 0,
+    }
+}
+
+// Helper function: Takes &str, but deref coercion lets us pass &MyBox<String>
+fn hello(name: &str) {
+    println!("Hello, {}!", name);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sum_list_walks_every_box() {
+        let list = Cons(1, Box::new(Cons(2, Box::new(Cons(3, Box::new(Nil))))));
+        assert_eq!(sum_list(&list), 6);
+        assert_eq!(sum_list(&Nil), 0);
+    }
+
+    #[test]
+    fn my_box_derefs_to_its_value() {
+        let boxed = MyBox::new(String::from("hi"));
+        assert_eq!(*boxed, "hi");
+        assert_eq!(boxed.len(), 2); // auto-deref to String::len
+    }
+
+    #[test]
+    fn weak_parent_does_not_keep_it_alive() {
+        let leaf = Rc::new(TreeNode {
+            value: 1,
+            parent: RefCell::new(Weak::new()),
+            children: RefCell::new(vec![]),
+        });
+        {
+            let branch = Rc::new(TreeNode {
+                value: 2,
+                parent: RefCell::new(Weak::new()),
+                children: RefCell::new(vec![Rc::clone(&leaf)]),
+            });
+            *leaf.parent.borrow_mut() = Rc::downgrade(&branch);
+            assert_eq!(leaf.parent.borrow().upgrade().map(|p| p.value), Some(2));
+        }
+        assert!(leaf.parent.borrow().upgrade().is_none());
+    }
+}
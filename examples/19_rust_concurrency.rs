@@ -0,0 +1,147 @@
+// 🦀 Rust Concurrency Exercises
+// Type out each exercise to build muscle memory!
+// Stuck on one? `cargo run -- hint 19` shows a hint; add `--level 2` or `--level 3` for more.
+// Worked example: count words across chunks of text on several threads,
+// first by sending results over channels, then by sharing one map behind a Mutex.
+// The ownership rules from file 13 are what make this safe: the compiler
+// refuses to let two threads touch the same data without synchronization.
+
+// Until you fill in main, the imports, TEXT and the helpers below go unused
+#![allow(dead_code, unused_imports)]
+
+use std::collections::HashMap;
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+const TEXT: &str = "the cat sat on the mat
+the dog sat on the log
+a cat and a dog met on a mat
+the mat was red
+the log was wet
+the end";
+
+// How many threads to split the work across
+const WORKERS: usize = 3;
+
+fn main() {
+    println!("=== Exercise 1: Spawning a Thread ===");
+    // TODO: Spawn a thread that prints "hello 1" to "hello 3", and wait for it with
+    // join(). Then say it finished.
+
+    println!("\n=== Exercise 2: move Closures Give the Thread Its Data ===");
+    // TODO: Try to borrow a local from a thread (uncomment to see error)
+    // let numbers = vec![1, 2, 3];
+    // let handle = thread::spawn(|| {
+    //     println!("{:?}", numbers); // Error: closure may outlive the current function,
+    // });                            //        but it borrows `numbers`
+    // handle.join().unwrap();
+
+    // TODO: Fix it with move, and get a value back through join(): push 4 onto the
+    // numbers, move them into a thread that sums them, and print the sum
+
+    println!("\n=== Exercise 3: Split the Work Into Chunks ===");
+    // TODO: Chunk the lines of TEXT with split_into_chunks, so each of the WORKERS gets
+    // roughly the same amount. Print each chunk.
+
+    println!("\n=== Exercise 4: Word Count With Channels (mpsc) ===");
+    // TODO: Make a channel. Then spawn one worker per chunk, each with its own clone of
+    // the sender. Each worker counts its own chunk and sends the partial result.
+    // (Run it a few times: the workers don't always finish in the same order.)
+
+    // TODO: Receive every partial count and merge them into 'totals' on the main
+    // thread, then print_top
+
+    println!("\n=== Exercise 5: A Data Race the Compiler Rejects ===");
+    // TODO: Try to let every thread write into the same HashMap (uncomment to see error)
+    // let mut shared = HashMap::new();
+    // let mut handles = Vec::new();
+    // for chunk in chunks.clone() {
+    //     handles.push(thread::spawn(|| {
+    //         for word in chunk.join(" ").split_whitespace() {
+    //             *shared.entry(word.to_string()).or_insert(0) += 1;
+    //             // Error: closure may outlive the current function, but it borrows `shared`
+    //             // Adding `move` doesn't help either:
+    //             // Error: use of moved value: `shared` (it can only move into ONE thread)
+    //         }
+    //     }));
+    // }
+    // Why? Two threads doing `+= 1` on the same entry at the same moment
+    // could both read 4 and both write 5. Rust makes that a compile error.
+
+    println!("\n=== Exercise 6: Word Count With Arc<Mutex<HashMap>> ===");
+    // TODO: Arc lets several threads own the map; Mutex lets one at a time change it.
+    // Spawn a thread per chunk again, but this time each one counts its chunk and merges
+    // it straight into a shared Arc<Mutex<HashMap<String, usize>>>. Join them all.
+
+    // TODO: Both approaches must agree: print_top the shared map, and compare it with
+    // the channel version's totals
+
+    println!("\n✅ All exercises complete!");
+}
+
+// Helper function: Split the lines of `text` into at most `parts` groups
+fn split_into_chunks(text: &str, parts: usize) -> Vec<Vec<String>> {
+    let lines: Vec<String> = text.lines().map(String::from).collect();
+    let size = lines.len().div_ceil(parts.max(1)).max(1);
+    lines.chunks(size).map(|chunk| chunk.to_vec()).collect()
+}
+
+// Helper function: Count words in one piece of text (single-threaded, no sharing)
+fn count_words(text: &str) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for word in text.split_whitespace() {
+        *counts.entry(word.to_string()).or_insert(0) += 1;
+    }
+    counts
+}
+
+// Helper function: Add a partial count into the running totals
+fn merge_counts(totals: &mut HashMap<String, usize>, partial: HashMap<String, usize>) {
+    for (word, count) in partial {
+        *totals.entry(word).or_insert(0) += count;
+    }
+}
+
+// Helper function: Print the most common words (ties broken alphabetically)
+fn print_top(counts: &HashMap<String, usize>) {
+    let mut sorted: Vec<(&String, &usize)> = counts.iter().collect();
+    sorted.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+
+    for (word, count) in sorted.iter().take(5) {
+        println!("  {:>4}: {}", word, count);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunks_cover_every_line() {
+        let chunks = split_into_chunks(TEXT, WORKERS);
+        let lines: usize = chunks.iter().map(Vec::len).sum();
+
+        assert_eq!(chunks.len(), WORKERS);
+        assert_eq!(lines, TEXT.lines().count());
+    }
+
+    #[test]
+    fn chunk_edge_cases() {
+        assert!(split_into_chunks("", 3).is_empty());
+        assert_eq!(split_into_chunks("one line", 4).len(), 1);
+        // Zero workers is treated as one instead of dividing by zero
+        assert_eq!(split_into_chunks("a\nb", 0).len(), 1);
+    }
+
+    #[test]
+    fn merging_partials_matches_counting_everything() {
+        let mut totals = HashMap::new();
+        merge_counts(&mut totals, count_words("a b a"));
+        merge_counts(&mut totals, count_words("b c"));
+
+        assert_eq!(totals, count_words("a b a b c"));
+        assert_eq!(totals["a"], 2);
+        assert_eq!(totals["b"], 2);
+    }
+}
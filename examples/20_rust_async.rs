@@ -0,0 +1,240 @@
+// 🦀 Rust Async/Await Exercises
+// Type out each exercise to build muscle memory!
+// Stuck on one? `cargo run -- hint 20` shows a hint; add `--level 2` or `--level 3` for more.
+// An `async fn` doesn't run when you call it: it returns a Future, a value that
+// can be polled until it's done. Something has to do the polling, that's the executor.
+// Real projects use a runtime like tokio; here we hand-roll a tiny one with only std,
+// so nothing is hidden.
+
+// The executor and the futures below are only used once main calls them
+#![allow(dead_code, unused_imports)]
+
+use std::future::Future;
+use std::pin::{Pin, pin};
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+// The synchronous functions from file 07, for comparison
+fn add(num_a: f32, num_b: f32) -> f32 {
+    if num_a == 0.0 || num_b == 0.0 {
+        0.0
+    } else {
+        num_a + num_b
+    }
+}
+
+fn say_hi() {
+    println!("I am saying Hi");
+}
+
+// ...and their async equivalents. Same bodies, but the caller gets a Future back
+async fn add_async(num_a: f32, num_b: f32) -> f32 {
+    yield_now().await; // Pretend we had to wait for something
+    add(num_a, num_b)
+}
+
+async fn say_hi_async() {
+    println!("I am saying Hi (asynchronously)");
+}
+
+fn main() {
+    println!("=== Exercise 1: Synchronous Version (file 07) ===");
+    // TODO: Call the plain functions, they run immediately. Print add(1.0, 2.0), then
+    // call say_hi.
+
+    println!("\n=== Exercise 2: Async Functions Are Lazy ===");
+    // TODO: Calling an async fn only builds a Future, nothing is printed yet. Call
+    // say_hi_async, keep the future, and print that it hasn't run.
+
+    // TODO: Hand it to the executor, block_on, to actually run it
+
+    // TODO: Forgetting to run a future is a warning, not an error (uncomment to see)
+    // say_hi_async(); // Warning: unused implementer of `Future` that must be used
+
+    println!("\n=== Exercise 3: .await Inside async ===");
+    // TODO: .await gives you the output of another future. In an async block run by
+    // block_on, await add_async(1.0, 2.0), then add 10.0 to that the same way.
+
+    // TODO: .await only works inside async code (uncomment to see error)
+    // let sum = add_async(1.0, 2.0).await; // Error: `await` is only allowed inside `async` functions and blocks
+
+    println!("\n=== Exercise 4: Watching the Executor Poll ===");
+    // TODO: A future that isn't ready returns Pending, then gets polled again.
+    // block_on a Countdown with 3 remaining, and print what it finishes with.
+
+    println!("\n=== Exercise 5: Running Sequentially vs Joining ===");
+    // TODO: Awaiting one after the other: task B doesn't start until A is done.
+    // Print "-- one after the other --", then await worker("A", 3) and worker("B", 3)
+    // in turn inside one async block.
+
+    // TODO: Joining: both tasks make progress, taking turns every time one yields.
+    // Print "-- joined --", then run the same two workers with join_all. Print the results.
+
+    println!("\n=== Exercise 6: Async Blocks Capture Like Closures ===");
+    // TODO: async move takes ownership of what it uses, just like move || closures.
+    // Move a String 'name' ("Ferris") into an async block that formats a greeting.
+
+    println!("\n✅ All exercises complete!");
+}
+
+// Helper function: A task that does `steps` pieces of work, yielding after each one
+async fn worker(name: &str, steps: u32) -> String {
+    for step in 1..=steps {
+        println!("  {} step {}", name, step);
+        yield_now().await;
+    }
+    format!("{} done", name)
+}
+
+// ----------------------------------------------------------------------------
+// The mini executor. Everything below is what a runtime like tokio does for you.
+// ----------------------------------------------------------------------------
+
+// A Waker tells the executor "poll me again". Ours just unparks the waiting thread.
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+// Run a future to completion on the current thread
+fn block_on<F: Future>(future: F) -> F::Output {
+    // poll() needs Pin<&mut F>: the future must not move once polling starts
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            // Sleep until someone calls wake(). If wake() already happened, park returns at once.
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+// A future that is Pending the first time it's polled and Ready the second time.
+// This gives other futures a turn, like tokio::task::yield_now().
+struct YieldNow {
+    yielded: bool,
+}
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.yielded {
+            Poll::Ready(())
+        } else {
+            self.yielded = true;
+            cx.waker().wake_by_ref(); // "I'm not done, but poll me again right away"
+            Poll::Pending
+        }
+    }
+}
+
+fn yield_now() -> YieldNow {
+    YieldNow { yielded: false }
+}
+
+// A hand-written future that prints every time it's polled
+struct Countdown {
+    remaining: u32,
+}
+
+impl Future for Countdown {
+    type Output = &'static str;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<&'static str> {
+        if self.remaining == 0 {
+            println!("  polled: Ready!");
+            Poll::Ready("liftoff")
+        } else {
+            println!("  polled: Pending ({} left)", self.remaining);
+            self.remaining -= 1;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+// Poll several futures in turn until all of them are done, keeping their outputs in order
+struct JoinAll<T> {
+    futures: Vec<Pin<Box<dyn Future<Output = T>>>>,
+    results: Vec<Option<T>>,
+}
+
+fn join_all<T>(futures: Vec<Pin<Box<dyn Future<Output = T>>>>) -> JoinAll<T> {
+    let results = futures.iter().map(|_| None).collect();
+    JoinAll { futures, results }
+}
+
+// The futures are pinned in their own Boxes, and the results are plain values we never
+// pin, so moving a JoinAll is always fine. Saying so lets `&mut *self` work without
+// asking every T to be Unpin (Vec<Option<T>> on its own is only Unpin if T is).
+impl<T> Unpin for JoinAll<T> {}
+
+impl<T> Future for JoinAll<T> {
+    type Output = Vec<T>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Vec<T>> {
+        let this = &mut *self;
+
+        for (future, result) in this.futures.iter_mut().zip(this.results.iter_mut()) {
+            if result.is_none()
+                && let Poll::Ready(output) = future.as_mut().poll(cx)
+            {
+                *result = Some(output);
+            }
+        }
+
+        if this.results.iter().all(Option::is_some) {
+            Poll::Ready(this.results.iter_mut().filter_map(Option::take).collect())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn block_on_runs_async_fns() {
+        assert_eq!(block_on(add_async(1.0, 2.0)), 3.0);
+        assert_eq!(block_on(add_async(0.0, 2.0)), 0.0);
+    }
+
+    #[test]
+    fn block_on_handles_pending_futures() {
+        assert_eq!(block_on(Countdown { remaining: 5 }), "liftoff");
+        assert_eq!(block_on(Countdown { remaining: 0 }), "liftoff");
+    }
+
+    #[test]
+    fn join_all_keeps_results_in_order() {
+        // B finishes first, but its result still comes second
+        let results = block_on(join_all(vec![
+            Box::pin(worker("A", 3)),
+            Box::pin(worker("B", 1)),
+        ]));
+        assert_eq!(results, vec!["A done", "B done"]);
+    }
+
+    #[test]
+    fn join_all_of_nothing() {
+        let results: Vec<String> = block_on(join_all(vec![]));
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn join_all_of_outputs_that_are_not_unpin() {
+        let future: Pin<Box<dyn Future<Output = std::marker::PhantomPinned>>> =
+            Box::pin(async { std::marker::PhantomPinned });
+        assert_eq!(block_on(join_all(vec![future])).len(), 1);
+    }
+}
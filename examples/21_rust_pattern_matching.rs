@@ -0,0 +1,218 @@
+// 🦀 Rust Pattern Matching Exercises
+// Type out each exercise to build muscle memory!
+// Stuck on one? `cargo run -- hint 21` shows a hint; add `--level 2` or `--level 3` for more.
+// Files 09 (tuples) and 11 (enums) showed the basic `match`. Patterns can do a lot more:
+// guards, bindings, nested destructuring, slices, and they show up outside `match` too.
+// Each exercise lists its expected output, so you can check your typing.
+
+// The types and helpers below wait for main to use them
+#![allow(dead_code)]
+
+#[derive(Debug)]
+enum Color {
+    Rgb(u8, u8, u8),
+    Hsv(u16, u8, u8),
+}
+
+#[derive(Debug)]
+enum Message {
+    Quit,
+    Move { x: i32, y: i32 },
+    Write(String),
+    Paint(Color),
+}
+
+struct Point3 {
+    x: i32,
+    y: i32,
+    z: i32,
+}
+
+fn main() {
+    println!("=== Exercise 1: Match Guards ===");
+    // TODO: A guard is an extra `if` on an arm. The arm only matches if the guard is true.
+    // Read classify below, then run it on -3, 0, 4 and 7.
+    // Expected:
+    // -3 is negative
+    // 0 is zero
+    // 4 is even
+    // 7 is odd
+
+    println!("\n=== Exercise 2: @ Bindings ===");
+    // TODO: `name @ pattern` tests a value against a pattern AND keeps it in a variable.
+    // Match the ages 5, 15 and 42 against the ranges 0..=12 and 13..=19.
+    // Expected:
+    // 5 is a child
+    // 15 is a teenager
+    // 42 is an adult
+
+    // TODO: @ works inside struct-like enum variants too. With if let, check whether a
+    // Message::Move { x: 3, y: 40 } has an x from 0 to 9.
+    // Expected: Small step right (3), y = 40
+
+    println!("\n=== Exercise 3: Nested Enum Destructuring ===");
+    // TODO: Patterns nest as deep as your types do. Read describe_message, then run it
+    // on a Vec with one of each Message (paint in Rgb and in Hsv, and move down by 1).
+    // Expected:
+    // Paint red
+    // Paint hue 120 at 100% saturation
+    // Write "hi"
+    // Move down by 1
+    // Quit
+
+    println!("\n=== Exercise 4: Struct Destructuring With .. ===");
+    // TODO: Make a Point3 at (1, 2, 0). Pull x out of it with a `let`, ignoring the
+    // rest with `..`
+    // Expected: x = 1
+
+    // TODO: Match on some fields with literals and bind others: is the point's z 0?
+    // Expected: On the floor at (1, 2)
+
+    println!("\n=== Exercise 5: Slice Patterns ===");
+    // TODO: Match on the length and shape of a slice. Read describe_slice, then run it
+    // on an empty slice, on [7] and on [1, 2, 3, 4].
+    // Expected:
+    // empty
+    // just 7
+    // 1 ... 4 (2 in between)
+
+    // TODO: `rest @ ..` binds the remaining elements as a smaller slice. sum_slice uses
+    // that to recurse; sum [1, 2, 3, 4] with it.
+    // Expected: Sum: 10
+
+    println!("\n=== Exercise 6: if let, while let and let Chains ===");
+    // TODO: `if let` is a match with one interesting arm. Print a favorite animal from
+    // an Option<&str>.
+    // Expected: Favorite: crab
+
+    // TODO: `while let` loops until the pattern stops matching. Pop a vec![1, 2, 3]
+    // until it's empty.
+    // Expected:
+    // Popped 3
+    // Popped 2
+    // Popped 1
+
+    // TODO: Chain several `let`s and conditions with && (edition 2024). From
+    // Some("42"), get the text, parse it and check that it's over 10, all in one `if`.
+    // Expected: Parsed a big number: 42
+
+    // TODO: `let ... else` binds the value or leaves the function (here: the block).
+    // Parse "7" and "seven" in a loop, and `continue` when parsing fails.
+    // Expected:
+    // Got 7
+    // 'seven' is not a number
+
+    println!("\n=== Exercise 7: Refutable vs Irrefutable Patterns ===");
+    // TODO: An irrefutable pattern always matches, so plain `let` (and function params)
+    // accept it. Destructure the tuple (1, 2) into a and b.
+    // Expected: a = 1, b = 2
+
+    // TODO: A refutable pattern might not match. Plain `let` rejects it (uncomment to see error)
+    // let maybe: Option<i32> = None;
+    // let Some(value) = maybe; // Error: refutable pattern in local binding
+    // println!("{}", value);
+
+    // TODO: So refutable patterns need if let, let-else, while let or match. Match on
+    // an Option<i32> that is None.
+    // Expected: Nothing to see
+
+    println!("\n✅ All exercises complete!");
+}
+
+// Helper function: Guards pick between arms that share the same pattern (`n`).
+// Arms are tried top to bottom, so order matters.
+fn classify(n: i32) -> &'static str {
+    match n {
+        n if n < 0 => "negative",
+        0 => "zero",
+        n if n % 2 == 0 => "even",
+        _ => "odd",
+    }
+}
+
+// Helper function: Nested destructuring, with a literal pattern before the general one
+fn describe_message(msg: &Message) -> String {
+    match msg {
+        Message::Quit => String::from("Quit"),
+        // unsigned_abs, not -y: negating i32::MIN overflows
+        Message::Move { x: 0, y } if *y < 0 => format!("Move down by {}", y.unsigned_abs()),
+        Message::Move { x, y } => format!("Move to ({}, {})", x, y),
+        Message::Write(text) => format!("Write {:?}", text),
+        Message::Paint(Color::Rgb(255, 0, 0)) => String::from("Paint red"),
+        Message::Paint(Color::Rgb(r, g, b)) => format!("Paint rgb({}, {}, {})", r, g, b),
+        Message::Paint(Color::Hsv(_, _, 0)) => String::from("Paint black"),
+        Message::Paint(Color::Hsv(hue, saturation, _)) => {
+            format!("Paint hue {} at {}% saturation", hue, saturation)
+        }
+    }
+}
+
+// Helper function: Slice patterns match on length as well as contents
+fn describe_slice(items: &[i32]) -> String {
+    match items {
+        [] => String::from("empty"),
+        [one] => format!("just {}", one),
+        [first, last] => format!("{} and {}", first, last),
+        [first, middle @ .., last] => {
+            format!("{} ... {} ({} in between)", first, last, middle.len())
+        }
+    }
+}
+
+// Helper function: Recursion on [head, tail @ ..], like lists in functional languages
+fn sum_slice(items: &[i32]) -> i32 {
+    match items {
+        [] => 0,
+        [head, tail @ ..] => head + sum_slice(tail),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn guards_are_tried_in_order() {
+        assert_eq!(classify(-2), "negative");
+        assert_eq!(classify(0), "zero");
+        assert_eq!(classify(10), "even");
+        assert_eq!(classify(-1), "negative");
+        assert_eq!(classify(9), "odd");
+    }
+
+    #[test]
+    fn nested_messages() {
+        let red = Message::Paint(Color::Rgb(255, 0, 0));
+        let teal = Message::Paint(Color::Rgb(0, 128, 128));
+
+        assert_eq!(describe_message(&red), "Paint red");
+        assert_eq!(describe_message(&teal), "Paint rgb(0, 128, 128)");
+        assert_eq!(
+            describe_message(&Message::Paint(Color::Hsv(200, 50, 0))),
+            "Paint black"
+        );
+        assert_eq!(
+            describe_message(&Message::Move { x: 0, y: 5 }),
+            "Move to (0, 5)"
+        );
+        assert_eq!(
+            describe_message(&Message::Move { x: 0, y: -2 }),
+            "Move down by 2"
+        );
+        assert_eq!(
+            describe_message(&Message::Move { x: 0, y: i32::MIN }),
+            "Move down by 2147483648"
+        );
+    }
+
+    #[test]
+    fn slices_of_every_length() {
+        assert_eq!(describe_slice(&[]), "empty");
+        assert_eq!(describe_slice(&[1]), "just 1");
+        assert_eq!(describe_slice(&[1, 2]), "1 and 2");
+        assert_eq!(describe_slice(&[1, 2, 3]), "1 ... 3 (1 in between)");
+
+        assert_eq!(sum_slice(&[]), 0);
+        assert_eq!(sum_slice(&[-1, 1, 5]), 5);
+    }
+}
@@ -0,0 +1,39 @@
+// The `geometry` module. `mod geometry;` in main.rs looks for geometry.rs
+// or, as here, geometry/mod.rs (handy once a module has children of its own).
+
+// A child module in its own file: geometry/shapes.rs. It's private, so main.rs
+// can't write `geometry::shapes::Circle`...
+mod shapes;
+
+// ...but re-exporting with `pub use` makes `geometry::Circle` work instead.
+// Callers don't need to know how geometry is split into files.
+pub use shapes::{Circle, Rectangle};
+
+// A child module written inline. `pub mod` lets main.rs reach `geometry::units::...`
+pub mod units {
+    pub const CM_PER_METER: f64 = 100.0;
+
+    pub fn cm_to_m(cm: f64) -> f64 {
+        cm / CM_PER_METER
+    }
+}
+
+// Private to geometry, but geometry's children (shapes) may still use it
+fn square(x: f64) -> f64 {
+    x * x
+}
+
+// A plain function in the module: callers write `geometry::total_area(...)`
+pub fn total_area(circles: &[Circle], rectangles: &[Rectangle]) -> f64 {
+    let circles: f64 = circles.iter().map(Circle::area).sum();
+    let rectangles: f64 = rectangles.iter().map(Rectangle::area).sum();
+    circles + rectangles
+}
+
+// `self::` means "start from this module", the same as plain `shapes::` here
+pub fn unit_square() -> Rectangle {
+    self::shapes::Rectangle {
+        width: 1.0,
+        height: 1.0,
+    }
+}
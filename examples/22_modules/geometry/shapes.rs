@@ -0,0 +1,51 @@
+// This file is the `geometry::shapes` module. It was pulled in by `mod shapes;`
+// in geometry/mod.rs, so it doesn't need (and mustn't have) a `mod` line for itself.
+
+// `super` is the parent module (geometry), where the private `square` helper lives.
+// Children can see their parent's private items; the parent can't see a child's.
+use super::square;
+
+// `pub` on the struct makes the type visible outside this module,
+// but every field is still private unless it gets its own `pub`.
+#[derive(Debug)]
+pub struct Circle {
+    radius: f64,
+}
+
+impl Circle {
+    // The only way to make a Circle from outside: fields are private, so
+    // `Circle { radius: -1.0 }` won't compile in main.rs. This lets `new` reject bad input.
+    pub fn new(radius: f64) -> Option<Circle> {
+        if radius >= 0.0 {
+            Some(Circle { radius })
+        } else {
+            None
+        }
+    }
+
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    pub fn area(&self) -> f64 {
+        std::f64::consts::PI * square(self.radius)
+    }
+}
+
+// All-`pub` fields: anyone can build one with a struct literal and read the fields
+#[derive(Debug)]
+pub struct Rectangle {
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rectangle {
+    pub fn area(&self) -> f64 {
+        self.width * self.height
+    }
+
+    // Visible to the whole crate, but not part of the "public" API
+    pub(crate) fn is_square(&self) -> bool {
+        self.width == self.height
+    }
+}
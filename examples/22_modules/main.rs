@@ -0,0 +1,98 @@
+// 🦀 Rust Modules Exercises
+// Type out each exercise to build muscle memory!
+// Stuck on one? `cargo run -- hint 22` shows a hint; add `--level 2` or `--level 3` for more.
+// The first multi-file example. Run it like the others: `cargo run --example 22_modules`
+//
+//   examples/22_modules/
+//   ├── main.rs            the crate root: `mod geometry;` starts the module tree here
+//   └── geometry/
+//       ├── mod.rs         the `geometry` module
+//       └── shapes.rs      `geometry::shapes`, declared inside mod.rs
+//
+// Modules are declared with `mod`, made visible with `pub`, and brought into scope with `use`.
+// The lines up to main are already written, because the tests at the bottom need them too.
+
+// Nothing in geometry is used until you write main
+#![allow(dead_code, unused_imports)]
+
+// Declare the geometry module. Rust only compiles files that a `mod` line points to.
+mod geometry;
+
+// `use` creates a shortcut, so you can write `Circle` instead of `geometry::Circle`
+use geometry::units::{self, CM_PER_METER};
+use geometry::{Circle, Rectangle};
+
+// This example is its own crate, separate from src/lib.rs. Shared code from the
+// library is reached through the library's name, like any other dependency.
+use rust_tutorial::collections::Stack;
+
+fn main() {
+    println!("=== Exercise 1: Paths ===");
+    // TODO: Call geometry::units::cm_to_m by its full path from this module, to turn
+    // 250cm into meters
+
+    // TODO: `crate::` starts from the crate root (this file), wherever you are.
+    // Convert 50cm again, with a path that starts with crate::.
+
+    // TODO: Use the names imported at the top of the file: print CM_PER_METER, and
+    // convert 30cm with units::cm_to_m
+
+    println!("\n=== Exercise 2: Private Fields and Constructors ===");
+    // TODO: Circle's field is private, so build one with radius 2.0 through Circle::new.
+    // Print its radius and area.
+
+    // TODO: new() can refuse bad input, because nobody can skip it. Try a radius of -1.0.
+
+    // TODO: Try building or reading the field directly (uncomment to see error)
+    // let bad = Circle { radius: 1.0 }; // Error: field `radius` of struct `Circle` is private
+    // println!("{}", circle.radius); // Error: field `radius` of struct `Circle` is private
+
+    println!("\n=== Exercise 3: Public Fields ===");
+    // TODO: Rectangle's fields are all pub, so a struct literal works. Make a 3 x 4
+    // rectangle and print its sides and area.
+
+    // TODO: pub(crate) items work anywhere in this crate. Ask whether the rectangle,
+    // and geometry::unit_square(), are squares.
+
+    println!("\n=== Exercise 4: Re-exports and Private Modules ===");
+    // TODO: total_area is a function in geometry itself. Add up the circle and the
+    // rectangle with it.
+
+    // TODO: Circle was re-exported with `pub use`, but the shapes module itself is private
+
+    println!("\n=== Exercise 5: Code From the Library Crate ===");
+    // TODO: Stack comes from src/collections/stack.rs, re-exported by src/collections/mod.rs.
+    // Push "circle" then "rectangle" onto a history Stack, and peek at the last one.
+
+    println!("\n✅ All exercises complete!");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn circles_reject_negative_radius() {
+        assert!(Circle::new(-0.1).is_none());
+        assert_eq!(Circle::new(0.0).unwrap().area(), 0.0);
+    }
+
+    #[test]
+    fn total_area_adds_every_shape() {
+        let rectangles = [
+            Rectangle {
+                width: 2.0,
+                height: 3.0,
+            },
+            geometry::unit_square(),
+        ];
+
+        assert_eq!(geometry::total_area(&[], &rectangles), 7.0);
+        assert_eq!(geometry::total_area(&[], &[]), 0.0);
+    }
+
+    #[test]
+    fn unit_conversion() {
+        assert_eq!(units::cm_to_m(250.0), 2.5);
+    }
+}
@@ -0,0 +1,390 @@
+// 🦀 Rust File I/O Exercises
+// Type out each exercise to build muscle memory!
+// Stuck on one? `cargo run -- hint 23` shows a hint; add `--level 2` or `--level 3` for more.
+// We read examples/data/users.csv into the User struct from file 10_1, write the users
+// back out as JSON, and read that JSON in again. Errors are handled the way file 15 does it:
+// one error enum, From<io::Error>, and `?`.
+//
+// About serde: real projects derive the JSON conversions instead of writing them. By
+// default this file writes them by hand with the small JSON module in the library
+// (src/json.rs), so the tutorial needs no dependencies. Turn on the `serde` feature to
+// derive them instead:
+//
+//     cargo run --example 23_rust_file_io --features serde
+//
+// Search for `feature = "serde"` to see the two versions side by side. The CSV is split
+// by hand either way; the `csv` crate would turn that into one line too:
+//
+//     let users: Vec<User> = csv::Reader::from_path(path)?.deserialize().collect()?;
+
+// Until main calls the helpers below, they and csv_path go unused
+#![allow(dead_code, unused_imports, unused_variables)]
+
+#[cfg(not(feature = "serde"))]
+use rust_tutorial::json::{self, Value};
+#[cfg(not(feature = "serde"))]
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct User {
+    username: String,
+    email: String,
+    age: i32,
+    active: bool,
+}
+
+// Everything that can go wrong with a users file
+#[derive(Debug)]
+enum UserFileError {
+    Io(io::Error),
+    WrongColumnCount {
+        line: usize,
+        found: usize,
+    },
+    InvalidField {
+        line: usize,
+        field: &'static str,
+        value: String,
+    },
+    #[cfg(not(feature = "serde"))]
+    Json(json::ParseError),
+    #[cfg(feature = "serde")]
+    Json(serde_json::Error),
+    NotAList, // valid JSON, but not a list of anything
+    NotAUser {
+        index: usize,
+    },
+}
+
+impl fmt::Display for UserFileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UserFileError::Io(err) => write!(f, "could not access file: {}", err),
+            UserFileError::WrongColumnCount { line, found } => {
+                write!(f, "line {}: expected 4 columns, found {}", line, found)
+            }
+            UserFileError::InvalidField { line, field, value } => {
+                write!(f, "line {}: '{}' is not a valid {}", line, value, field)
+            }
+            UserFileError::Json(err) => write!(f, "invalid JSON: {}", err),
+            UserFileError::NotAList => write!(f, "expected a list of users"),
+            UserFileError::NotAUser { index } => write!(f, "entry #{} is not a user", index),
+        }
+    }
+}
+
+impl std::error::Error for UserFileError {}
+
+impl From<io::Error> for UserFileError {
+    fn from(err: io::Error) -> Self {
+        UserFileError::Io(err)
+    }
+}
+
+#[cfg(not(feature = "serde"))]
+impl From<json::ParseError> for UserFileError {
+    fn from(err: json::ParseError) -> Self {
+        UserFileError::Json(err)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<serde_json::Error> for UserFileError {
+    fn from(err: serde_json::Error) -> Self {
+        UserFileError::Json(err)
+    }
+}
+
+// The std fallback for what serde's derive writes for us
+#[cfg(not(feature = "serde"))]
+impl User {
+    // {"active": true, "age": 30, "email": "...", "username": "..."}
+    fn to_json(&self) -> Value {
+        let mut map = BTreeMap::new();
+        map.insert(
+            String::from("username"),
+            Value::from(self.username.as_str()),
+        );
+        map.insert(String::from("email"), Value::from(self.email.as_str()));
+        map.insert(String::from("age"), Value::Number(self.age as f64));
+        map.insert(String::from("active"), Value::from(self.active));
+        Value::Object(map)
+    }
+
+    // None if a field is missing or has the wrong type. Like serde, an age must be a
+    // whole number that fits in an i32: `as` would quietly turn 30.7 into 30.
+    fn from_json(value: &Value) -> Option<User> {
+        let age = value.get("age")?.as_f64()?;
+        if age.fract() != 0.0 || age < i32::MIN as f64 || age > i32::MAX as f64 {
+            return None;
+        }
+
+        Some(User {
+            username: value.get("username")?.as_str()?.to_string(),
+            email: value.get("email")?.as_str()?.to_string(),
+            age: age as i32,
+            active: value.get("active")?.as_bool()?,
+        })
+    }
+}
+
+fn main() {
+    let csv_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples/data/users.csv");
+    let json_path = std::env::temp_dir().join("rust_tutorial_users.json");
+
+    println!("=== Exercise 1: Reading a Whole File ===");
+    // TODO: read_to_string loads the file into one String. Read csv_path and print
+    // how many bytes and lines it has.
+
+    println!("\n=== Exercise 2: Missing Files ===");
+    // TODO: A missing file is an Err, not a crash. Check its kind() to react to it:
+    // read "no_such_file.csv" and say it's not found when the kind is NotFound.
+
+    println!("\n=== Exercise 3: Reading Line by Line ===");
+    // TODO: BufReader reads in chunks, so big files don't need to fit in memory.
+    // Open csv_path and print each line with its line number.
+
+    println!("\n=== Exercise 4: CSV Into Structs ===");
+    // TODO: read_users opens the file and parses every row (see the helpers below).
+    // Keep the result as 'users' and print each one. On an error, print it and return.
+
+    // TODO: Bad rows turn into errors that say exactly what's wrong. Run parse_users on
+    // "username,email,age,active\nDan,dan@x.com,old,true" and on "header\nEve,eve@x.com".
+
+    println!("\n=== Exercise 5: Writing JSON ===");
+    // TODO: Turn the users into JSON and write them to json_path with write_json, which
+    // uses a BufWriter. Then print the file.
+
+    println!("\n=== Exercise 6: Reading the JSON Back ===");
+    // TODO: Round trip: what read_json reads should equal what we wrote
+
+    println!("\n=== Exercise 7: A Missing File Means No Users Yet ===");
+    // TODO: load_users treats NotFound as "empty", but still reports real problems.
+    // Remove json_path (ignoring the Result), then load_users from it.
+
+    println!("\n✅ All exercises complete!");
+}
+
+// Helper function: One CSV row -> User. This simple split doesn't handle quoted
+// commas ("Smith, Jr."); the `csv` crate does.
+fn parse_user(line_number: usize, line: &str) -> Result<User, UserFileError> {
+    let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+    let [username, email, age, active] = fields[..] else {
+        return Err(UserFileError::WrongColumnCount {
+            line: line_number,
+            found: fields.len(),
+        });
+    };
+
+    let invalid = |field, value: &str| UserFileError::InvalidField {
+        line: line_number,
+        field,
+        value: value.to_string(),
+    };
+
+    Ok(User {
+        username: username.to_string(),
+        email: email.to_string(),
+        age: age.parse().map_err(|_| invalid("age", age))?,
+        active: active.parse().map_err(|_| invalid("active flag", active))?,
+    })
+}
+
+// Helper function: Skip the header row and blank lines, parse the rest
+fn parse_users(text: &str) -> Result<Vec<User>, UserFileError> {
+    let mut users = Vec::new();
+
+    for (index, line) in text.lines().enumerate().skip(1) {
+        if line.trim().is_empty() {
+            continue;
+        }
+        users.push(parse_user(index + 1, line)?);
+    }
+    Ok(users)
+}
+
+// Helper function: `?` turns the io::Error into UserFileError::Io
+fn read_users(path: &Path) -> Result<Vec<User>, UserFileError> {
+    let text = fs::read_to_string(path)?;
+    parse_users(&text)
+}
+
+// Helper function: BufWriter batches small writes into fewer system calls.
+// flush() makes sure everything reached the file (and reports any error).
+#[cfg(not(feature = "serde"))]
+fn write_json(path: &Path, users: &[User]) -> Result<(), UserFileError> {
+    let list = Value::Array(users.iter().map(User::to_json).collect());
+
+    let mut writer = BufWriter::new(fs::File::create(path)?);
+    writeln!(writer, "{}", list.to_pretty_string())?;
+    writer.flush()?;
+    Ok(())
+}
+
+// Helper function: The same, with serde. The derived Serialize does the to_json part.
+#[cfg(feature = "serde")]
+fn write_json(path: &Path, users: &[User]) -> Result<(), UserFileError> {
+    let mut writer = BufWriter::new(fs::File::create(path)?);
+    serde_json::to_writer_pretty(&mut writer, users)?;
+    writeln!(writer)?;
+    writer.flush()?;
+    Ok(())
+}
+
+// Helper function: Both the file and each entry can be wrong
+#[cfg(not(feature = "serde"))]
+fn read_json(path: &Path) -> Result<Vec<User>, UserFileError> {
+    let value = json::parse(&fs::read_to_string(path)?)?;
+    let items = value.as_array().ok_or(UserFileError::NotAList)?;
+
+    items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| User::from_json(item).ok_or(UserFileError::NotAUser { index }))
+        .collect()
+}
+
+// Helper function: The same, with serde. Reading plain Values first, then each User,
+// lets us still say which entry was wrong.
+#[cfg(feature = "serde")]
+fn read_json(path: &Path) -> Result<Vec<User>, UserFileError> {
+    let value: serde_json::Value = serde_json::from_str(&fs::read_to_string(path)?)?;
+    let serde_json::Value::Array(items) = value else {
+        return Err(UserFileError::NotAList);
+    };
+
+    items
+        .into_iter()
+        .enumerate()
+        .map(|(index, item)| {
+            serde_json::from_value(item).map_err(|_| UserFileError::NotAUser { index })
+        })
+        .collect()
+}
+
+// Helper function: Only NotFound is "fine". A corrupted file is still an error.
+fn load_users(path: &Path) -> Result<Vec<User>, UserFileError> {
+    match read_json(path) {
+        Err(UserFileError::Io(err)) if err.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn alice() -> User {
+        User {
+            username: String::from("Alice"),
+            email: String::from("alice@gmail.com"),
+            age: 30,
+            active: true,
+        }
+    }
+
+    fn temp_file(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!(
+            "rust_tutorial_{}_{}.json",
+            name,
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn parses_rows_after_the_header() {
+        let users = parse_users("username,email,age,active\n\nAlice, alice@gmail.com, 30, true\n");
+        assert_eq!(users.unwrap(), vec![alice()]);
+        assert!(parse_users("username,email,age,active").unwrap().is_empty());
+    }
+
+    #[test]
+    fn bad_rows_are_reported_with_their_line() {
+        assert!(matches!(
+            parse_user(3, "Bob,bob@gmail.com,25"),
+            Err(UserFileError::WrongColumnCount { line: 3, found: 3 })
+        ));
+        assert!(matches!(
+            parse_user(2, "Bob,bob@gmail.com,25,yes"),
+            Err(UserFileError::InvalidField {
+                line: 2,
+                field: "active flag",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let path = temp_file("round_trip");
+        let users = vec![
+            alice(),
+            User {
+                active: false,
+                ..alice()
+            },
+        ];
+
+        write_json(&path, &users).unwrap();
+        assert_eq!(read_json(&path).unwrap(), users);
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn missing_file_is_empty_but_corrupted_file_is_an_error() {
+        let path = temp_file("corrupted");
+        let _ = fs::remove_file(&path);
+        assert!(load_users(&path).unwrap().is_empty());
+
+        fs::write(&path, r#"[{"username": "x"}]"#).unwrap();
+        assert!(matches!(
+            load_users(&path),
+            Err(UserFileError::NotAUser { index: 0 })
+        ));
+
+        fs::write(&path, r#"{"username": "x"}"#).unwrap();
+        assert!(matches!(load_users(&path), Err(UserFileError::NotAList)));
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn ages_must_be_whole_numbers_that_fit() {
+        let path = temp_file("ages");
+        let user = |age: &str| {
+            format!(
+                r#"{{"username": "x", "email": "x@example.com", "age": {}, "active": true}}"#,
+                age
+            )
+        };
+
+        fs::write(&path, format!("[{}]", user("30"))).unwrap();
+        assert_eq!(read_json(&path).unwrap()[0].age, 30);
+
+        for age in ["30.7", "1e12", "-3000000000"] {
+            fs::write(&path, format!("[{}, {}]", user("30"), user(age))).unwrap();
+            assert!(
+                matches!(read_json(&path), Err(UserFileError::NotAUser { index: 1 })),
+                "age {} was accepted",
+                age
+            );
+        }
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn the_sample_file_parses() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples/data/users.csv");
+        let users = read_users(&path).unwrap();
+
+        assert_eq!(users.len(), 3);
+        assert_eq!(users[0], alice());
+    }
+}
@@ -0,0 +1,189 @@
+// 🦀 Rust Macros Exercises
+// Type out each exercise to build muscle memory!
+// Stuck on one? `cargo run -- hint 24` shows a hint; add `--level 2` or `--level 3` for more.
+// You've used macros since file 04: println!, format!, vec!. The `!` means "this
+// runs at compile time and writes code for you". macro_rules! lets you write your own:
+// each rule is a pattern on the left and the code to generate on the right.
+//
+// Macros must be defined before they're used, so they're at the top of the file.
+
+// my_vec! builds its Vec with push on purpose; clippy would rather we just called vec!
+#![allow(clippy::vec_init_then_push)]
+// The macros (and Shape) are only used once you've typed the exercises into main
+#![allow(unused_macros, unused_imports)]
+
+// The Shape enum from file 11, which now lives in the library
+use rust_tutorial::shapes::Shape;
+
+// A simplified vec!. Rules are tried top to bottom; the first one that matches wins.
+//
+// - `$x:expr` matches one expression and calls it $x
+// - `$( ... ),*` matches the inside zero or more times, separated by commas
+// - `$(,)?` allows an optional trailing comma
+// - in the output, `$( ... )*` repeats once for every $x that was matched
+macro_rules! my_vec {
+    () => {
+        Vec::new()
+    };
+    ($item:expr; $count:expr) => {{
+        let mut items = Vec::new();
+        items.resize($count, $item);
+        items
+    }};
+    ($($x:expr),+ $(,)?) => {{
+        let mut items = Vec::new();
+        $(
+            items.push($x);
+        )+
+        items
+    }};
+}
+
+// Words like `circle` in a pattern are matched literally, so the macro reads like a
+// tiny language. A rule can call the macro again: `square` reuses `rectangle`.
+macro_rules! make_shape {
+    (circle $radius:expr) => {
+        Shape::Circle { radius: $radius }
+    };
+    (rectangle $width:expr, $height:expr) => {
+        Shape::Rectangle {
+            width: $width,
+            height: $height,
+        }
+    };
+    (square $side:expr) => {
+        make_shape!(rectangle $side, $side)
+    };
+    (triangle $base:expr, $height:expr) => {
+        Shape::Triangle {
+            base: $base,
+            height: $height,
+        }
+    };
+}
+
+// stringify! turns the tokens it's given into a string literal, so we can print
+// the expression itself next to its value
+macro_rules! debug_print {
+    ($($value:expr),* $(,)?) => {
+        $(
+            println!("{} = {:?}", stringify!($value), $value);
+        )*
+    };
+}
+
+fn main() {
+    println!("=== Exercise 1: my_vec! ===");
+    // TODO: Use all three rules of my_vec!: build an empty Vec<i32>, three zeros, and
+    // 1, 2, 3. Print all three.
+
+    // TODO: Trace the expansion. Read this, no typing needed.
+    //
+    //     my_vec![1, 2, 3]
+    //
+    // 1. `()` doesn't match: there are tokens inside the brackets
+    // 2. `$item:expr; $count:expr` doesn't match: there's no `;`
+    // 3. `$($x:expr),+` matches, with $x = 1, then 2, then 3
+    //
+    // so the compiler replaces the macro call with:
+    //
+    //     {
+    //         let mut items = Vec::new();
+    //         items.push(1);
+    //         items.push(2);
+    //         items.push(3);
+    //         items
+    //     }
+    //
+    // The outer `{{ }}` in the macro is what makes this a block, and so an expression.
+
+    // TODO: Any expression works, including ones with commas inside parentheses.
+    // Build a Vec from String::from("a"), "b".to_uppercase() and format!("{}{}", 'c', 'd').
+
+    println!("\n=== Exercise 2: make_shape! ===");
+    // TODO: Build shapes with the mini-language: a circle of 5.0, a 10.0 by 5.0
+    // rectangle, a square of 3.0 and a 6.0 by 12.0 triangle. Print each with its area.
+
+    // TODO: Trace the expansion of the recursive rule. Read this, no typing needed.
+    //
+    //     make_shape!(square 3.0)
+    //  => make_shape!(rectangle 3.0, 3.0)        // the `square` rule
+    //  => Shape::Rectangle { width: 3.0, height: 3.0 }
+    //
+    // Careful: $side is pasted in twice. make_shape!(square next_size()) would
+    // call next_size() twice! A macro substitutes code, it doesn't evaluate it first.
+
+    // TODO: A shape name that no rule matches is a compile error (uncomment to see error)
+    // let hexagon = make_shape!(hexagon 2.0); // Error: no rules expected the token `hexagon`
+
+    println!("\n=== Exercise 3: debug_print! With Repetition ===");
+    // TODO: One println! per expression, each labelled with its own source code.
+    // debug_print! x = 5, x * 2, name = "Ferris", name.len() and numbers.
+
+    // TODO: Trace the expansion. Read this, no typing needed.
+    //
+    //     debug_print!(x, x * 2)
+    //
+    // $value matches `x` and then `x * 2`, so `$( println!(...); )*` repeats twice:
+    //
+    //     println!("{} = {:?}", "x", x);
+    //     println!("{} = {:?}", "x * 2", x * 2);
+    //
+    // Try `cargo expand --example 24_rust_macros` (a separate tool, installed with
+    // `cargo install cargo-expand`) to see every macro in this file fully expanded.
+
+    println!("\n=== Exercise 4: Hygiene ===");
+    // TODO: my_vec! has a variable called `items` inside it. Ours doesn't clash with it.
+    // Make your own 'items', build a my_vec! of it twice, and print both.
+    // Names made inside a macro live in their own scope, so the macro's `items`
+    // and ours are different variables even though they're spelled the same.
+
+    println!("\n✅ All exercises complete!");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn my_vec_matches_vec() {
+        let empty: Vec<u8> = my_vec![];
+        assert_eq!(empty, vec![]);
+        assert_eq!(my_vec!['x'; 2], vec!['x', 'x']);
+        assert_eq!(my_vec![1], vec![1]);
+        assert_eq!(my_vec![1, 2, 3,], vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn make_shape_builds_each_variant() {
+        assert_eq!(make_shape!(circle 1.0), Shape::Circle { radius: 1.0 });
+        assert_eq!(
+            make_shape!(square 2.0),
+            Shape::Rectangle {
+                width: 2.0,
+                height: 2.0
+            }
+        );
+        assert_eq!(make_shape!(triangle 4.0, 1.0).area(), 2.0);
+    }
+
+    #[test]
+    fn macro_arguments_are_pasted_not_evaluated() {
+        let mut calls = 0;
+        let mut next_size = || {
+            calls += 1;
+            calls as f64
+        };
+
+        // The square rule uses $side twice, so next_size runs twice
+        let shape = make_shape!(square next_size());
+        assert_eq!(
+            shape,
+            Shape::Rectangle {
+                width: 1.0,
+                height: 2.0
+            }
+        );
+        assert_eq!(calls, 2);
+    }
+}
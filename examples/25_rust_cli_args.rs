@@ -0,0 +1,197 @@
+// 🦀 Rust Command-Line Arguments Exercises
+// Type out each exercise to build muscle memory!
+// Stuck on one? `cargo run -- hint 25` shows a hint; add `--level 2` or `--level 3` for more.
+// A tiny calculator you run from the terminal:
+//
+//     cargo run --example 25_rust_cli_args -- add 2 3
+//
+// Everything after `--` is handed to the program. It brings together functions (file 07),
+// enums and match (file 11) and Result-based error handling (file 15).
+// Run the tests with: cargo test --example 25_rust_cli_args
+
+// main starts out empty, so the imports, USAGE and the helpers go unused
+#![allow(dead_code, unused_imports)]
+
+use std::env;
+use std::fmt;
+use std::process;
+
+const USAGE: &str = "\
+Usage: cargo run --example 25_rust_cli_args -- <op> <a> <b>
+  <op> is one of: add, sub, mul, div (or +, -, x, /)
+  e.g. cargo run --example 25_rust_cli_args -- div 7 2";
+
+// One variant per thing the calculator can do
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+// Every way the input can be wrong
+#[derive(Debug, PartialEq)]
+enum CalcError {
+    WrongArgCount(usize), // `add 2`
+    UnknownOp(String),    // `pow 2 3`
+    NotANumber(String),   // `add two 3`
+    DivideByZero,         // `div 1 0`
+}
+
+impl fmt::Display for CalcError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CalcError::WrongArgCount(found) => {
+                write!(
+                    f,
+                    "expected 3 arguments (an op and two numbers), got {}",
+                    found
+                )
+            }
+            CalcError::UnknownOp(op) => write!(f, "'{}' is not an operation", op),
+            CalcError::NotANumber(text) => write!(f, "'{}' is not a number", text),
+            CalcError::DivideByZero => write!(f, "can't divide by zero"),
+        }
+    }
+}
+
+impl std::error::Error for CalcError {}
+
+fn main() {
+    println!("=== Exercise 1: Reading the Arguments ===");
+    // TODO: env::args() is an iterator of Strings. The first one is the program itself.
+    // Collect them into a Vec, then print the program and the arguments after it.
+
+    // TODO: Usually you skip the program name straight away. Collect the rest into
+    // 'user_args' and print how many there are.
+
+    println!("\n=== Exercise 2: Words Into an Enum ===");
+    // TODO: parse_op turns the first argument into an Op, or explains why it can't.
+    // Try it on "add", "x" and "pow".
+
+    println!("\n=== Exercise 3: Parsing Numbers With Result ===");
+    // TODO: str::parse returns a Result; parse_number swaps its error for ours.
+    // Try it on "2.5" and on "two".
+
+    // TODO: Calling .unwrap() on bad input would crash the program (uncomment to see error)
+    // let n: f64 = "two".parse().unwrap(); // Error: panics with "called `Result::unwrap()` on an `Err` value"
+
+    println!("\n=== Exercise 4: Putting It Together With ? ===");
+    // TODO: calculate parses and applies in one go. Try it on some made-up arguments:
+    // add 2 3, div 7 2, div 1 0, pow 2 3, mul six 7 and sub 1. calculate wants
+    // &[String], so turn each sample into a Vec<String> first. Print each answer or error.
+
+    println!("\n=== Exercise 5: The Real Command Line ===");
+    // TODO: Run the calculator on your own arguments. On bad input, explain what went
+    // wrong on stderr, show the USAGE and exit with a non-zero status, like real tools do.
+    // Never typed any? Then there's nothing to calculate, which is fine.
+
+    println!("\n✅ All exercises complete!");
+}
+
+// Helper function: symbols work too, but `*` would be expanded by the shell into
+// a list of files, so multiplication is `x`
+fn parse_op(word: &str) -> Result<Op, CalcError> {
+    match word {
+        "add" | "+" => Ok(Op::Add),
+        "sub" | "-" => Ok(Op::Sub),
+        "mul" | "x" => Ok(Op::Mul),
+        "div" | "/" => Ok(Op::Div),
+        other => Err(CalcError::UnknownOp(other.to_string())),
+    }
+}
+
+// Helper function: map_err keeps the Ok value and converts the error
+fn parse_number(text: &str) -> Result<f64, CalcError> {
+    text.parse::<f64>()
+        .map_err(|_| CalcError::NotANumber(text.to_string()))
+}
+
+// Helper function: a slice pattern checks the count and names the pieces at once
+fn parse_args(args: &[String]) -> Result<(Op, f64, f64), CalcError> {
+    match args {
+        [op, a, b] => Ok((parse_op(op)?, parse_number(a)?, parse_number(b)?)),
+        _ => Err(CalcError::WrongArgCount(args.len())),
+    }
+}
+
+// Helper function: one arm per Op. Adding a variant makes this a compile error
+// until it's handled here too.
+fn apply(op: Op, a: f64, b: f64) -> Result<f64, CalcError> {
+    match op {
+        Op::Add => Ok(a + b),
+        Op::Sub => Ok(a - b),
+        Op::Mul => Ok(a * b),
+        Op::Div if b == 0.0 => Err(CalcError::DivideByZero),
+        Op::Div => Ok(a / b),
+    }
+}
+
+// Helper function: each ? returns early with the first error it meets
+fn calculate(args: &[String]) -> Result<f64, CalcError> {
+    let (op, a, b) = parse_args(args)?;
+    apply(op, a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(words: &[&str]) -> Vec<String> {
+        words.iter().map(|word| word.to_string()).collect()
+    }
+
+    #[test]
+    fn ops_by_name_or_symbol() {
+        assert_eq!(parse_op("add"), Ok(Op::Add));
+        assert_eq!(parse_op("x"), Ok(Op::Mul));
+        assert_eq!(parse_op("/"), Ok(Op::Div));
+        assert_eq!(
+            parse_op("Add"),
+            Err(CalcError::UnknownOp(String::from("Add")))
+        );
+    }
+
+    #[test]
+    fn bad_arguments_are_errors() {
+        assert_eq!(calculate(&args(&[])), Err(CalcError::WrongArgCount(0)));
+        assert_eq!(
+            calculate(&args(&["add", "1", "2", "3"])),
+            Err(CalcError::WrongArgCount(4))
+        );
+        assert_eq!(
+            calculate(&args(&["add", "1", "two"])),
+            Err(CalcError::NotANumber(String::from("two")))
+        );
+        // The op is checked before the numbers
+        assert_eq!(
+            calculate(&args(&["pow", "x", "y"])),
+            Err(CalcError::UnknownOp(String::from("pow")))
+        );
+    }
+
+    #[test]
+    fn calculating() {
+        assert_eq!(calculate(&args(&["add", "2", "3"])), Ok(5.0));
+        assert_eq!(calculate(&args(&["-", "2", "3.5"])), Ok(-1.5));
+        assert_eq!(calculate(&args(&["mul", "-4", "2"])), Ok(-8.0));
+        assert_eq!(calculate(&args(&["div", "7", "2"])), Ok(3.5));
+        assert_eq!(
+            calculate(&args(&["div", "1", "0"])),
+            Err(CalcError::DivideByZero)
+        );
+    }
+
+    #[test]
+    fn error_messages() {
+        assert_eq!(
+            CalcError::WrongArgCount(1).to_string(),
+            "expected 3 arguments (an op and two numbers), got 1"
+        );
+        assert_eq!(
+            CalcError::NotANumber(String::from("six")).to_string(),
+            "'six' is not a number"
+        );
+    }
+}
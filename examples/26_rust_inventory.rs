@@ -0,0 +1,429 @@
+// 🦀 Rust Inventory Exercises
+// Type out each exercise to build muscle memory!
+// Stuck on one? `cargo run -- hint 26` shows a hint; add `--level 2` or `--level 3` for more.
+// A bigger one: a shop inventory kept in a HashMap<String, Item>, driven by a menu you
+// answer on stdin. It combines structs (file 10_1), collections (file 16) and error
+// handling (file 15), and it's the first program here that remembers things between
+// one input and the next.
+//
+// Watch where each Item lives: it moves *into* the map when it's added, is borrowed
+// while you look at it, and moves back *out* when it's removed.
+// Run the tests with: cargo test --example 26_rust_inventory
+
+// The inventory is only used once main does something with it
+#![allow(dead_code, unused_imports)]
+
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Item {
+    name: String,
+    quantity: u32,
+    price: f64, // per item
+}
+
+// Every way a change to the inventory can be refused
+#[derive(Debug, PartialEq)]
+enum InventoryError {
+    AlreadyExists(String),
+    NotFound(String),
+    NotEnough {
+        name: String,
+        have: u32,
+        wanted: u32,
+    },
+    // The new quantity wouldn't fit in a u32
+    TooMany {
+        name: String,
+        have: u32,
+        adding: u32,
+    },
+}
+
+impl fmt::Display for InventoryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InventoryError::AlreadyExists(name) => {
+                write!(f, "there is already an item called '{}'", name)
+            }
+            InventoryError::NotFound(name) => write!(f, "there is no item called '{}'", name),
+            InventoryError::NotEnough { name, have, wanted } => {
+                write!(
+                    f,
+                    "can't take {} '{}', there are only {}",
+                    wanted, name, have
+                )
+            }
+            InventoryError::TooMany { name, have, adding } => {
+                write!(
+                    f,
+                    "can't add {} '{}', there are already {} and the limit is {}",
+                    adding,
+                    name,
+                    have,
+                    u32::MAX
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for InventoryError {}
+
+// The map's key is the item's name, so looking an item up by name is fast
+struct Inventory {
+    items: HashMap<String, Item>,
+}
+
+impl Inventory {
+    fn new() -> Inventory {
+        Inventory {
+            items: HashMap::new(),
+        }
+    }
+
+    // Takes the Item by value: after this, the inventory owns it
+    fn add(&mut self, item: Item) -> Result<(), InventoryError> {
+        match self.items.entry(item.name.clone()) {
+            Entry::Occupied(_) => Err(InventoryError::AlreadyExists(item.name)),
+            Entry::Vacant(slot) => {
+                slot.insert(item);
+                Ok(())
+            }
+        }
+    }
+
+    // Hands the Item back to the caller, who owns it from now on
+    fn remove(&mut self, name: &str) -> Result<Item, InventoryError> {
+        self.items
+            .remove(name)
+            .ok_or_else(|| InventoryError::NotFound(name.to_string()))
+    }
+
+    // Borrows: the Item stays in the map
+    fn get(&self, name: &str) -> Option<&Item> {
+        self.items.get(name)
+    }
+
+    // Adds to the stock (positive `change`) or takes from it (negative), and returns
+    // the new quantity. Taking more than there is, or adding past u32::MAX, leaves the
+    // item unchanged.
+    fn update(&mut self, name: &str, change: i32) -> Result<u32, InventoryError> {
+        let item = self
+            .items
+            .get_mut(name)
+            .ok_or_else(|| InventoryError::NotFound(name.to_string()))?;
+
+        // checked_add_signed fails both ways, so the sign says which error it was
+        let quantity = item.quantity.checked_add_signed(change).ok_or_else(|| {
+            if change < 0 {
+                InventoryError::NotEnough {
+                    name: name.to_string(),
+                    have: item.quantity,
+                    wanted: change.unsigned_abs(),
+                }
+            } else {
+                InventoryError::TooMany {
+                    name: name.to_string(),
+                    have: item.quantity,
+                    adding: change.unsigned_abs(),
+                }
+            }
+        })?;
+        item.quantity = quantity;
+        Ok(quantity)
+    }
+
+    fn total_value(&self) -> f64 {
+        self.items
+            .values()
+            .map(|item| item.quantity as f64 * item.price)
+            .fold(0.0, |total, value| total + value)
+    }
+
+    // A HashMap has no order of its own, so sort by name for a stable report
+    fn report(&self) -> String {
+        let mut items: Vec<&Item> = self.items.values().collect();
+        items.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut report = format!(
+            "{:<12} {:>5} {:>8} {:>9}\n",
+            "item", "qty", "price", "value"
+        );
+        for item in items {
+            report += &format!(
+                "{:<12} {:>5} {:>8.2} {:>9.2}\n",
+                item.name,
+                item.quantity,
+                item.price,
+                item.quantity as f64 * item.price
+            );
+        }
+        report += &format!("Total value: {:.2}\n", self.total_value());
+        report
+    }
+}
+
+fn main() {
+    println!("=== Exercise 1: Items ===");
+    // TODO: An Item is an ordinary struct that owns its name. Make 'apples': 10 of
+    // them at 0.5 each. Print it with {:?}.
+
+    println!("\n=== Exercise 2: Moving Items Into the Map ===");
+    // TODO: add takes the Item by value, so it moves into the inventory. Make a mutable
+    // Inventory, add apples, then add 4 pears at 0.75.
+
+    // TODO: apples now belongs to the inventory (uncomment to see error)
+    // println!("{:?}", apples); // Error: borrow of moved value: `apples`
+
+    // TODO: Adding the same name twice is refused, and the error gives the name back.
+    // Try adding another Item called "pears", and print the error.
+
+    println!("\n=== Exercise 3: Borrowing and Updating ===");
+    // TODO: get borrows an Item without taking it out of the map. Print how many
+    // apples there are.
+
+    // TODO: update uses get_mut to change the Item where it is. Print what you get from
+    // adding 5 apples, taking away 10 pears, and adding 1 plum.
+
+    println!("\n=== Exercise 4: Moving Items Back Out ===");
+    // TODO: remove returns the Item itself, so we own it again. Take the pears out,
+    // print them, then check whether the inventory still has them.
+
+    // TODO: ...and can add them back
+
+    println!("\n=== Exercise 5: A Report ===");
+    // TODO: Print every item, sorted by name, with its value. That's inventory.report().
+
+    println!("\n=== Exercise 6: The Menu Loop ===");
+    // TODO: Now you drive it. The loop keeps going until you pick quit or the input
+    // ends (Ctrl-D), so running this with nothing piped in finishes straight away.
+    // Call run_menu with the inventory, a locked stdin and stdout, and print any error.
+
+    println!("\n✅ All exercises complete!");
+}
+
+// Helper function: reads and handles menu choices until "quit" or the end of the input.
+// It's generic over where the lines come from and go to, so the tests can script it.
+fn run_menu(
+    inventory: &mut Inventory,
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+) -> io::Result<()> {
+    loop {
+        writeln!(output, "\n1) add  2) list  3) update  4) remove  5) quit")?;
+        let Some(choice) = prompt(input, output, "Choose: ")? else {
+            break;
+        };
+
+        match choice.as_str() {
+            "1" | "add" => {
+                let Some(item) = read_item(input, output)? else {
+                    break;
+                };
+                match inventory.add(item) {
+                    Ok(()) => writeln!(output, "Added.")?,
+                    Err(e) => writeln!(output, "Error: {}", e)?,
+                }
+            }
+            "2" | "list" => write!(output, "{}", inventory.report())?,
+            "3" | "update" => {
+                let Some(name) = prompt(input, output, "Name: ")? else {
+                    break;
+                };
+                let Some(change) = prompt(input, output, "Change (e.g. 5 or -2): ")? else {
+                    break;
+                };
+                match change.parse::<i32>() {
+                    Ok(change) => match inventory.update(&name, change) {
+                        Ok(quantity) => writeln!(output, "{} {} left.", quantity, name)?,
+                        Err(e) => writeln!(output, "Error: {}", e)?,
+                    },
+                    Err(_) => writeln!(output, "Error: '{}' is not a whole number", change)?,
+                }
+            }
+            "4" | "remove" => {
+                let Some(name) = prompt(input, output, "Name: ")? else {
+                    break;
+                };
+                match inventory.remove(&name) {
+                    Ok(item) => writeln!(output, "Removed {} {}.", item.quantity, item.name)?,
+                    Err(e) => writeln!(output, "Error: {}", e)?,
+                }
+            }
+            "5" | "quit" => break,
+            other => writeln!(output, "'{}' isn't on the menu", other)?,
+        }
+    }
+
+    writeln!(output, "Bye!")
+}
+
+// Helper function: asks for the three fields of an Item. A number that doesn't parse
+// is asked for again. None means the input ended halfway through.
+fn read_item(input: &mut impl BufRead, output: &mut impl Write) -> io::Result<Option<Item>> {
+    let Some(name) = prompt(input, output, "Name: ")? else {
+        return Ok(None);
+    };
+    let Some(quantity) = prompt_number(input, output, "Quantity: ")? else {
+        return Ok(None);
+    };
+    let Some(price) = prompt_number(input, output, "Price: ")? else {
+        return Ok(None);
+    };
+    Ok(Some(Item {
+        name,
+        quantity,
+        price,
+    }))
+}
+
+// Helper function: generic over any type that can be parsed, like u32 and f64
+fn prompt_number<T: std::str::FromStr>(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    question: &str,
+) -> io::Result<Option<T>> {
+    loop {
+        let Some(answer) = prompt(input, output, question)? else {
+            return Ok(None);
+        };
+        match answer.parse() {
+            Ok(number) => return Ok(Some(number)),
+            Err(_) => writeln!(output, "'{}' is not a valid number, try again", answer)?,
+        }
+    }
+}
+
+// Helper function: shows `question`, then reads one trimmed line. read_line returns 0
+// bytes only at the end of the input, which is how Ctrl-D shows up.
+fn prompt(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    question: &str,
+) -> io::Result<Option<String>> {
+    write!(output, "{}", question)?;
+    output.flush()?;
+
+    let mut line = String::new();
+    if input.read_line(&mut line)? == 0 {
+        writeln!(output)?;
+        return Ok(None);
+    }
+    Ok(Some(line.trim().to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(name: &str, quantity: u32, price: f64) -> Item {
+        Item {
+            name: name.to_string(),
+            quantity,
+            price,
+        }
+    }
+
+    // Runs the menu on a scripted session and returns everything it printed
+    fn session(inventory: &mut Inventory, script: &str) -> String {
+        let mut output = Vec::new();
+        run_menu(inventory, &mut script.as_bytes(), &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn add_get_and_remove() {
+        let mut inventory = Inventory::new();
+        inventory.add(item("nails", 100, 0.05)).unwrap();
+
+        assert_eq!(inventory.get("nails").map(|item| item.quantity), Some(100));
+        assert_eq!(
+            inventory.add(item("nails", 1, 1.0)),
+            Err(InventoryError::AlreadyExists(String::from("nails")))
+        );
+        assert_eq!(inventory.remove("nails"), Ok(item("nails", 100, 0.05)));
+        assert_eq!(
+            inventory.remove("nails"),
+            Err(InventoryError::NotFound(String::from("nails")))
+        );
+    }
+
+    #[test]
+    fn update_never_goes_below_zero() {
+        let mut inventory = Inventory::new();
+        inventory.add(item("bolts", 3, 0.2)).unwrap();
+
+        assert_eq!(inventory.update("bolts", 2), Ok(5));
+        assert_eq!(inventory.update("bolts", -5), Ok(0));
+        assert_eq!(
+            inventory.update("bolts", -1),
+            Err(InventoryError::NotEnough {
+                name: String::from("bolts"),
+                have: 0,
+                wanted: 1
+            })
+        );
+        assert_eq!(inventory.get("bolts").unwrap().quantity, 0);
+        assert!(inventory.update("screws", 1).is_err());
+    }
+
+    #[test]
+    fn update_never_goes_above_u32_max() {
+        let mut inventory = Inventory::new();
+        inventory.add(item("washers", u32::MAX, 0.01)).unwrap();
+
+        assert_eq!(
+            inventory.update("washers", 1),
+            Err(InventoryError::TooMany {
+                name: String::from("washers"),
+                have: u32::MAX,
+                adding: 1
+            })
+        );
+        assert_eq!(inventory.get("washers").unwrap().quantity, u32::MAX);
+        assert_eq!(inventory.update("washers", -1), Ok(u32::MAX - 1));
+    }
+
+    #[test]
+    fn report_is_sorted_with_a_total() {
+        let mut inventory = Inventory::new();
+        inventory.add(item("pears", 2, 1.5)).unwrap();
+        inventory.add(item("apples", 4, 0.25)).unwrap();
+
+        let report = inventory.report();
+        let lines: Vec<&str> = report.lines().collect();
+        assert!(lines[1].starts_with("apples"));
+        assert!(lines[2].starts_with("pears"));
+        assert_eq!(lines[3], "Total value: 4.00");
+        assert_eq!(Inventory::new().total_value(), 0.0);
+    }
+
+    #[test]
+    fn menu_session() {
+        let mut inventory = Inventory::new();
+        let script = "1\nrope\nlots\n3\n2.5\n3\nrope\n-1\n9\n2\n5\n";
+        let output = session(&mut inventory, script);
+
+        assert!(output.contains("'lots' is not a valid number, try again"));
+        assert!(output.contains("2 rope left."));
+        assert!(output.contains("'9' isn't on the menu"));
+        assert!(output.contains("rope             2"));
+        assert!(output.ends_with("Bye!\n"));
+        assert_eq!(inventory.get("rope"), Some(&item("rope", 2, 2.5)));
+    }
+
+    #[test]
+    fn menu_stops_at_the_end_of_the_input() {
+        let mut inventory = Inventory::new();
+        assert!(session(&mut inventory, "").ends_with("Bye!\n"));
+
+        // Halfway through adding an item: nothing is added
+        let output = session(&mut inventory, "1\nrope\n");
+        assert!(output.ends_with("Bye!\n"));
+        assert!(inventory.get("rope").is_none());
+    }
+}
@@ -0,0 +1,111 @@
+// 🦀 Capstone: a todo list on the command line
+//
+//   cargo run -p todo_cli -- add Buy milk
+//   cargo run -p todo_cli -- list
+//   cargo run -p todo_cli -- done 1
+//   cargo run -p todo_cli -- remove 1
+//
+// Tasks are saved to todo.json in the current directory
+// (set TODO_FILE to use a different file).
+//
+// Ties the chapters together: structs (Task), enums (Status, Command),
+// collections (Vec<Task>), error handling (StoreError + ?) and file I/O.
+
+mod store;
+mod task;
+
+use std::env;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use store::{StoreError, TaskStore};
+
+const USAGE: &str = "\
+Usage:
+  todo_cli add <title>
+  todo_cli list
+  todo_cli done <id>
+  todo_cli remove <id>";
+
+enum Command {
+    Add(String),
+    List,
+    Done(u32),
+    Remove(u32),
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let (name, rest) = args.split_first().ok_or("missing command")?;
+
+    match name.as_str() {
+        "add" if rest.is_empty() => Err(String::from("`add` needs a title")),
+        "add" => Ok(Command::Add(rest.join(" "))),
+        "list" => Ok(Command::List),
+        "done" => parse_id(rest).map(Command::Done),
+        "remove" => parse_id(rest).map(Command::Remove),
+        other => Err(format!("unknown command `{}`", other)),
+    }
+}
+
+fn parse_id(rest: &[String]) -> Result<u32, String> {
+    match rest {
+        [id] => id
+            .parse()
+            .map_err(|_| format!("`{}` is not a valid task id", id)),
+        _ => Err(String::from("expected exactly one task id")),
+    }
+}
+
+fn run(command: Command, store: &mut TaskStore) -> Result<(), StoreError> {
+    match command {
+        Command::Add(title) => {
+            let task = store.add(title)?;
+            println!("Added: {}", task);
+            store.save()?;
+        }
+        Command::List => {
+            if store.tasks().is_empty() {
+                println!("Nothing to do! Add a task with `add <title>`.");
+            }
+            for task in store.tasks() {
+                println!("{}", task);
+            }
+        }
+        Command::Done(id) => {
+            let task = store.complete(id)?;
+            println!("Completed: {}", task);
+            store.save()?;
+        }
+        Command::Remove(id) => {
+            let task = store.remove(id)?;
+            println!("Removed: {}", task);
+            store.save()?;
+        }
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let command = match parse_args(&args) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("Error: {}\n\n{}", message, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let path = env::var_os("TODO_FILE")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("todo.json"));
+
+    let result = TaskStore::load(&path).and_then(|mut store| run(command, &mut store));
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use crate::task::{Status, Task};
+use rust_tutorial::json::{self, Value};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// Everything that can go wrong while loading, changing or saving tasks
+#[derive(Debug)]
+pub enum StoreError {
+    Io(io::Error),
+    Json(json::ParseError),
+    BadTask(usize), // index of the entry in the file that we couldn't understand
+    NotFound(u32),
+    NoIdsLeft, // the highest id in use is already u32::MAX
+}
+
+impl fmt::Display for StoreError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StoreError::Io(err) => write!(f, "could not access the task file: {}", err),
+            StoreError::Json(err) => write!(f, "task file is corrupted: {}", err),
+            StoreError::BadTask(index) => write!(f, "task #{} in the file is malformed", index),
+            StoreError::NotFound(id) => write!(f, "no task with id {}", id),
+            StoreError::NoIdsLeft => write!(f, "no task ids left (the highest is {})", u32::MAX),
+        }
+    }
+}
+
+impl std::error::Error for StoreError {}
+
+impl From<io::Error> for StoreError {
+    fn from(err: io::Error) -> Self {
+        StoreError::Io(err)
+    }
+}
+
+impl From<json::ParseError> for StoreError {
+    fn from(err: json::ParseError) -> Self {
+        StoreError::Json(err)
+    }
+}
+
+// All tasks, plus the file they are saved in
+pub struct TaskStore {
+    path: PathBuf,
+    tasks: Vec<Task>,
+}
+
+impl TaskStore {
+    // A missing file is not an error: it just means no tasks yet
+    pub fn load(path: &Path) -> Result<TaskStore, StoreError> {
+        let tasks = match fs::read_to_string(path) {
+            Ok(text) => parse_tasks(&text)?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(TaskStore {
+            path: path.to_path_buf(),
+            tasks,
+        })
+    }
+
+    pub fn save(&self) -> Result<(), StoreError> {
+        let list = Value::Array(self.tasks.iter().map(Task::to_json).collect());
+        fs::write(&self.path, list.to_pretty_string() + "\n")?;
+        Ok(())
+    }
+
+    pub fn tasks(&self) -> &[Task] {
+        &self.tasks
+    }
+
+    // New ids continue after the highest one in use
+    pub fn add(&mut self, title: String) -> Result<&Task, StoreError> {
+        let highest = self.tasks.iter().map(|task| task.id).max().unwrap_or(0);
+        let id = highest.checked_add(1).ok_or(StoreError::NoIdsLeft)?;
+        self.tasks.push(Task::new(id, title));
+        Ok(self.tasks.last().unwrap())
+    }
+
+    pub fn complete(&mut self, id: u32) -> Result<&Task, StoreError> {
+        let task = self
+            .tasks
+            .iter_mut()
+            .find(|task| task.id == id)
+            .ok_or(StoreError::NotFound(id))?;
+        task.status = Status::Done;
+        Ok(task)
+    }
+
+    // Hands the removed task back to the caller (ownership moves out of the Vec)
+    pub fn remove(&mut self, id: u32) -> Result<Task, StoreError> {
+        let index = self
+            .tasks
+            .iter()
+            .position(|task| task.id == id)
+            .ok_or(StoreError::NotFound(id))?;
+        Ok(self.tasks.remove(index))
+    }
+}
+
+fn parse_tasks(text: &str) -> Result<Vec<Task>, StoreError> {
+    let value = json::parse(text)?;
+    let items = value.as_array().ok_or(StoreError::BadTask(0))?;
+
+    items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| Task::from_json(item).ok_or(StoreError::BadTask(index)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_file(name: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("todo_cli_{}_{}.json", name, std::process::id()));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn missing_file_means_empty_store() {
+        let store = TaskStore::load(&temp_file("missing")).unwrap();
+        assert!(store.tasks().is_empty());
+    }
+
+    #[test]
+    fn add_complete_remove_and_reload() {
+        let path = temp_file("round_trip");
+
+        let mut store = TaskStore::load(&path).unwrap();
+        store.add(String::from("write \"docs\"")).unwrap();
+        store.add(String::from("ship it")).unwrap();
+        store.complete(1).unwrap();
+        store.save().unwrap();
+
+        let mut store = TaskStore::load(&path).unwrap();
+        assert_eq!(store.tasks().len(), 2);
+        assert_eq!(store.tasks()[0].title, "write \"docs\"");
+        assert_eq!(store.tasks()[0].status, Status::Done);
+        assert_eq!(store.tasks()[1].status, Status::Todo);
+
+        let removed = store.remove(1).unwrap();
+        assert_eq!(removed.id, 1);
+        // Removing a lower id does not free it up for reuse
+        assert_eq!(store.add(String::from("next")).unwrap().id, 3);
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn unknown_ids_are_errors() {
+        let mut store = TaskStore::load(&temp_file("unknown")).unwrap();
+
+        assert!(matches!(store.complete(9), Err(StoreError::NotFound(9))));
+        assert!(matches!(store.remove(9), Err(StoreError::NotFound(9))));
+    }
+
+    #[test]
+    fn adding_after_the_highest_id_is_an_error() {
+        let path = temp_file("no_ids_left");
+        fs::write(
+            &path,
+            r#"[{"id": 4294967295, "title": "last", "status": "todo"}]"#,
+        )
+        .unwrap();
+
+        let mut store = TaskStore::load(&path).unwrap();
+        assert!(matches!(
+            store.add(String::from("one more")),
+            Err(StoreError::NoIdsLeft)
+        ));
+        assert_eq!(store.tasks().len(), 1);
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn corrupted_files_are_reported() {
+        assert!(matches!(parse_tasks("[{"), Err(StoreError::Json(_))));
+        assert!(matches!(parse_tasks("{}"), Err(StoreError::BadTask(0))));
+        assert!(matches!(
+            parse_tasks(r#"[{"id": 1, "title": "ok", "status": "todo"}, {"id": 2}]"#),
+            Err(StoreError::BadTask(1))
+        ));
+    }
+}
@@ -0,0 +1,78 @@
+use rust_tutorial::json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+
+// Where a task is at (enums chapter, file 11)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Status {
+    Todo,
+    Done,
+}
+
+impl Status {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Todo => "todo",
+            Status::Done => "done",
+        }
+    }
+
+    pub fn parse(text: &str) -> Option<Status> {
+        match text {
+            "todo" => Some(Status::Todo),
+            "done" => Some(Status::Done),
+            _ => None,
+        }
+    }
+}
+
+// One entry in the list (structs chapter, file 10_1)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Task {
+    pub id: u32,
+    pub title: String,
+    pub status: Status,
+}
+
+impl Task {
+    pub fn new(id: u32, title: String) -> Task {
+        Task {
+            id,
+            title,
+            status: Status::Todo,
+        }
+    }
+
+    // {"id": 1, "status": "todo", "title": "..."}
+    pub fn to_json(&self) -> Value {
+        let mut map = BTreeMap::new();
+        map.insert(String::from("id"), Value::from(self.id));
+        map.insert(String::from("title"), Value::from(self.title.as_str()));
+        map.insert(String::from("status"), Value::from(self.status.as_str()));
+        Value::Object(map)
+    }
+
+    // Returns None if a field is missing or has the wrong type
+    pub fn from_json(value: &Value) -> Option<Task> {
+        let id = value.get("id")?.as_f64()?;
+        if id < 0.0 || id.fract() != 0.0 || id > u32::MAX as f64 {
+            return None;
+        }
+
+        Some(Task {
+            id: id as u32,
+            title: value.get("title")?.as_str()?.to_string(),
+            status: Status::parse(value.get("status")?.as_str()?)?,
+        })
+    }
+}
+
+impl fmt::Display for Task {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mark = match self.status {
+            Status::Todo => ' ',
+            Status::Done => 'x',
+        };
+        write!(f, "[{}] {:>3}  {}", mark, self.id, self.title)
+    }
+}
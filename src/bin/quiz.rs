@@ -0,0 +1,520 @@
+// 🦀 Quiz: multiple-choice questions about each chapter
+//
+//   cargo run --bin quiz             list the quizzes, with your best scores
+//   cargo run --bin quiz -- 13       answer the questions for chapter 13
+//
+// Typing out the exercises builds muscle memory; the quiz checks you can *read* Rust,
+// with questions like "does this snippet compile?". The questions live in
+// quiz/questions.json and are built into the binary with include_str!. Scores are
+// recorded in .tutor/quiz.json, next to the tutor's progress.json.
+
+use rust_tutorial::json::{self, Value};
+use rust_tutorial::save_file;
+use std::collections::BTreeMap;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+const QUESTIONS: &str = include_str!("../../quiz/questions.json");
+
+const USAGE: &str = "\
+Usage:
+  cargo run --bin quiz
+  cargo run --bin quiz -- <chapter>";
+
+struct Chapter {
+    // Same numbering as the tutor's chapters: "06", "13", ...
+    id: String,
+    title: String,
+    questions: Vec<Question>,
+}
+
+struct Question {
+    text: String,
+    // Shown indented under the question, one entry per line
+    code: Vec<String>,
+    choices: Vec<String>,
+    // Index into `choices`. The file spells it as a letter: "a", "b", ...
+    answer: usize,
+    explanation: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Score {
+    last: u32,
+    best: u32,
+    total: u32,
+}
+
+// Scores per chapter, saved in .tutor/quiz.json:
+//
+//   {
+//     "13": { "best": 4, "last": 3, "total": 4 }
+//   }
+struct Scores {
+    path: PathBuf,
+    chapters: BTreeMap<String, Score>,
+}
+
+impl Scores {
+    // A missing file just means no quiz has been taken yet
+    fn load(path: &Path) -> io::Result<Scores> {
+        let chapters = save_file::load(path, parse_scores)?.unwrap_or_default();
+
+        Ok(Scores {
+            path: path.to_path_buf(),
+            chapters,
+        })
+    }
+
+    fn save(&self) -> io::Result<()> {
+        let mut map = BTreeMap::new();
+        for (chapter, score) in &self.chapters {
+            let mut fields = BTreeMap::new();
+            fields.insert(String::from("best"), Value::from(score.best));
+            fields.insert(String::from("last"), Value::from(score.last));
+            fields.insert(String::from("total"), Value::from(score.total));
+            map.insert(chapter.clone(), Value::Object(fields));
+        }
+
+        save_file::save(&self.path, &Value::Object(map))
+    }
+
+    fn get(&self, chapter: &str) -> Option<&Score> {
+        self.chapters.get(chapter)
+    }
+
+    // Returns true for a new best score
+    fn record(&mut self, chapter: &str, correct: u32, total: u32) -> bool {
+        let score = self.chapters.entry(chapter.to_string()).or_insert(Score {
+            last: 0,
+            best: 0,
+            total,
+        });
+
+        // The questions changed since last time, so the old best doesn't compare
+        if score.total != total {
+            score.best = 0;
+            score.total = total;
+        }
+
+        score.last = correct;
+        let new_best = correct > score.best;
+        score.best = score.best.max(correct);
+        new_best
+    }
+}
+
+fn main() -> ExitCode {
+    let chapters = match parse_chapters(QUESTIONS) {
+        Ok(chapters) => chapters,
+        Err(message) => {
+            eprintln!("quiz/questions.json is broken: {}", message);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.as_slice() {
+        [] => match load_scores(root) {
+            Some(scores) => {
+                list(&chapters, &scores);
+                ExitCode::SUCCESS
+            }
+            None => ExitCode::FAILURE,
+        },
+        [arg] if arg == "help" || arg == "--help" => {
+            println!("{}", USAGE);
+            ExitCode::SUCCESS
+        }
+        [id] => match (
+            chapters
+                .iter()
+                .find(|chapter| same_chapter(&chapter.id, id)),
+            load_scores(root),
+        ) {
+            (Some(chapter), Some(mut scores)) => quiz(chapter, &mut scores),
+            (None, Some(scores)) => {
+                eprintln!("No quiz for chapter `{}`. These ones have one:\n", id);
+                list(&chapters, &scores);
+                ExitCode::FAILURE
+            }
+            (_, None) => ExitCode::FAILURE,
+        },
+        _ => {
+            eprintln!("{}", USAGE);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// Only listing and quizzing read the file, so a damaged quiz.json doesn't get in
+// the way of `--help`
+fn load_scores(root: &Path) -> Option<Scores> {
+    match Scores::load(&root.join(".tutor").join("quiz.json")) {
+        Ok(scores) => Some(scores),
+        Err(err) => {
+            eprintln!("Could not read your quiz scores: {}", err);
+            None
+        }
+    }
+}
+
+//   13  Ownership and Borrowing    4 questions   best 3/4
+fn list(chapters: &[Chapter], scores: &Scores) {
+    for chapter in chapters {
+        let best = match scores.get(&chapter.id) {
+            Some(score) => format!("best {}/{}", score.best, score.total),
+            None => String::from("not taken yet"),
+        };
+        println!(
+            "{:>4}  {:<26} {:>2} questions   {}",
+            chapter.id,
+            chapter.title,
+            chapter.questions.len(),
+            best
+        );
+    }
+}
+
+fn quiz(chapter: &Chapter, scores: &mut Scores) -> ExitCode {
+    println!("=== Chapter {}: {} ===", chapter.id, chapter.title);
+
+    let result = take_quiz(chapter, &mut io::stdin().lock(), &mut io::stdout().lock());
+    let correct = match result {
+        Ok(Some(correct)) => correct,
+        Ok(None) => {
+            println!("\nQuiz stopped before the end, so no score was recorded.");
+            return ExitCode::SUCCESS;
+        }
+        Err(err) => {
+            eprintln!("Could not run the quiz: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let total = chapter.questions.len() as u32;
+    println!("\nScore: {}/{}", correct, total);
+    if scores.record(&chapter.id, correct, total) {
+        println!("🏆 New best for chapter {}!", chapter.id);
+    }
+
+    // Like the tutor's progress, a lost score shouldn't fail the quiz itself
+    if let Err(err) = scores.save() {
+        eprintln!("⚠️  Could not save your score: {}", err);
+    }
+    ExitCode::SUCCESS
+}
+
+// Asks every question in order. Returns the number answered correctly, or None if
+// the input ran out first (Ctrl-D, or nothing piped in).
+fn take_quiz(
+    chapter: &Chapter,
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+) -> io::Result<Option<u32>> {
+    let mut correct = 0;
+
+    for (number, question) in chapter.questions.iter().enumerate() {
+        writeln!(
+            output,
+            "\nQuestion {}/{}: {}",
+            number + 1,
+            chapter.questions.len(),
+            question.text
+        )?;
+        match ask(question, input, output)? {
+            Some(true) => correct += 1,
+            Some(false) => {}
+            None => return Ok(None),
+        }
+    }
+    Ok(Some(correct))
+}
+
+// Shows one question and reads answers until one is a valid choice.
+// Returns whether it was right, or None at the end of the input.
+fn ask(
+    question: &Question,
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+) -> io::Result<Option<bool>> {
+    if !question.code.is_empty() {
+        writeln!(output)?;
+        for line in &question.code {
+            writeln!(output, "    {}", line)?;
+        }
+    }
+    writeln!(output)?;
+    for (index, choice) in question.choices.iter().enumerate() {
+        writeln!(output, "  {}) {}", letter(index), choice)?;
+    }
+
+    let choice = loop {
+        write!(output, "> ")?;
+        output.flush()?;
+
+        let mut reply = String::new();
+        if input.read_line(&mut reply)? == 0 {
+            return Ok(None);
+        }
+        match choice_index(&reply, question.choices.len()) {
+            Some(choice) => break choice,
+            None => writeln!(
+                output,
+                "Please answer with a letter from a to {}.",
+                letter(question.choices.len() - 1)
+            )?,
+        }
+    };
+
+    if choice == question.answer {
+        writeln!(output, "✅ Correct! {}", question.explanation)?;
+    } else {
+        writeln!(
+            output,
+            "❌ Not quite, it's {}) {}. {}",
+            letter(question.answer),
+            question.choices[question.answer],
+            question.explanation
+        )?;
+    }
+    Ok(Some(choice == question.answer))
+}
+
+// "b", "B" and "2" all pick the second choice
+fn choice_index(reply: &str, count: usize) -> Option<usize> {
+    let reply = reply.trim().to_lowercase();
+    let index = match reply.parse::<usize>() {
+        Ok(number) => number.checked_sub(1)?,
+        Err(_) => {
+            let mut chars = reply.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c @ 'a'..='z'), None) => c as usize - 'a' as usize,
+                _ => return None,
+            }
+        }
+    };
+    (index < count).then_some(index)
+}
+
+fn letter(index: usize) -> char {
+    (b'a' + index as u8) as char
+}
+
+// "6" finds chapter "06", like the tutor's `run 6`
+fn same_chapter(id: &str, wanted: &str) -> bool {
+    match (id.parse::<u32>(), wanted.parse::<u32>()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => id == wanted,
+    }
+}
+
+fn parse_chapters(text: &str) -> Result<Vec<Chapter>, String> {
+    let value = json::parse(text).map_err(|err| err.to_string())?;
+    let chapters = value
+        .get("chapters")
+        .and_then(Value::as_array)
+        .ok_or("expected a \"chapters\" list")?;
+
+    chapters.iter().map(parse_chapter).collect()
+}
+
+fn parse_chapter(value: &Value) -> Result<Chapter, String> {
+    let id = string_field(value, "chapter").ok_or("every chapter needs a \"chapter\" number")?;
+    let title =
+        string_field(value, "title").ok_or_else(|| format!("chapter {} needs a \"title\"", id))?;
+    let questions = value
+        .get("questions")
+        .and_then(Value::as_array)
+        .filter(|questions| !questions.is_empty())
+        .ok_or_else(|| format!("chapter {} needs a \"questions\" list", id))?
+        .iter()
+        .enumerate()
+        .map(|(index, question)| {
+            parse_question(question)
+                .map_err(|message| format!("chapter {}, question {}: {}", id, index + 1, message))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Chapter {
+        id,
+        title,
+        questions,
+    })
+}
+
+fn parse_question(value: &Value) -> Result<Question, String> {
+    let text = string_field(value, "question").ok_or("missing \"question\"")?;
+    let code = string_list(value, "code").ok_or("\"code\" should be a list of lines")?;
+    let choices = string_list(value, "choices").ok_or("\"choices\" should be a list")?;
+    let explanation = string_field(value, "explanation").ok_or("missing \"explanation\"")?;
+
+    if choices.len() < 2 || choices.len() > 26 {
+        return Err(String::from("there should be between 2 and 26 choices"));
+    }
+    let answer = string_field(value, "answer")
+        .and_then(|answer| choice_index(&answer, choices.len()))
+        .ok_or("\"answer\" should be the letter of one of the choices")?;
+
+    Ok(Question {
+        text,
+        code,
+        choices,
+        answer,
+        explanation,
+    })
+}
+
+fn string_field(value: &Value, key: &str) -> Option<String> {
+    value.get(key).and_then(Value::as_str).map(String::from)
+}
+
+fn string_list(value: &Value, key: &str) -> Option<Vec<String>> {
+    value
+        .get(key)?
+        .as_array()?
+        .iter()
+        .map(|item| item.as_str().map(String::from))
+        .collect()
+}
+
+fn parse_scores(text: &str) -> Result<BTreeMap<String, Score>, String> {
+    let value = json::parse(text).map_err(|err| err.to_string())?;
+    let chapters = value.as_object().ok_or("expected an object of chapters")?;
+
+    let number = |score: &Value, key: &str| {
+        score
+            .get(key)
+            .and_then(Value::as_f64)
+            .filter(|n| n.fract() == 0.0 && *n >= 0.0)
+            .map(|n| n as u32)
+    };
+
+    chapters
+        .iter()
+        .map(|(chapter, score)| {
+            let parsed = Score {
+                last: number(score, "last")?,
+                best: number(score, "best")?,
+                total: number(score, "total")?,
+            };
+            Some((chapter.clone(), parsed))
+        })
+        .collect::<Option<_>>()
+        .ok_or_else(|| String::from("each chapter needs a \"best\", \"last\" and \"total\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn sample_chapter() -> Chapter {
+        let text = r#"{"chapters": [{
+            "chapter": "13",
+            "title": "Ownership",
+            "questions": [
+                {"question": "Q1", "code": [], "choices": ["Yes", "No"], "answer": "b",
+                 "explanation": "moved"},
+                {"question": "Q2", "code": ["let x = 5;"], "choices": ["Yes", "No"],
+                 "answer": "a", "explanation": "Copy"}
+            ]
+        }]}"#;
+        parse_chapters(text).unwrap().remove(0)
+    }
+
+    #[test]
+    fn the_built_in_questions_parse() {
+        let chapters = parse_chapters(QUESTIONS).unwrap();
+        assert!(chapters.iter().any(|chapter| chapter.id == "13"));
+
+        let mut ids: Vec<&str> = chapters.iter().map(|chapter| chapter.id.as_str()).collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), chapters.len(), "a chapter is listed twice");
+    }
+
+    #[test]
+    fn broken_questions_are_reported() {
+        let question = |answer: &str, choices: &str| {
+            let text = format!(
+                r#"{{"chapters": [{{"chapter": "01", "title": "T", "questions": [
+                    {{"question": "Q", "code": [], "choices": {}, "answer": "{}",
+                      "explanation": "E"}}
+                ]}}]}}"#,
+                choices, answer
+            );
+            parse_chapters(&text).map(|_| ())
+        };
+
+        assert_eq!(question("b", r#"["x", "y"]"#), Ok(()));
+        assert!(
+            question("c", r#"["x", "y"]"#)
+                .unwrap_err()
+                .contains("question 1")
+        );
+        assert!(question("a", r#"["x"]"#).is_err());
+        assert!(parse_chapters(r#"{"chapters": [{"chapter": "01", "title": "T"}]}"#).is_err());
+    }
+
+    #[test]
+    fn choices_by_letter_or_number() {
+        assert_eq!(choice_index("a\n", 3), Some(0));
+        assert_eq!(choice_index(" B ", 3), Some(1));
+        assert_eq!(choice_index("3", 3), Some(2));
+        assert_eq!(choice_index("d", 3), None);
+        assert_eq!(choice_index("0", 3), None);
+        assert_eq!(choice_index("ab", 3), None);
+        assert_eq!(choice_index("", 3), None);
+    }
+
+    #[test]
+    fn scores_right_answers_and_asks_again_after_bad_input() {
+        let chapter = sample_chapter();
+        let mut output = Vec::new();
+
+        let correct = take_quiz(&chapter, &mut "maybe\nb\nb\n".as_bytes(), &mut output);
+        assert_eq!(correct.unwrap(), Some(1));
+
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Please answer with a letter from a to b."));
+        assert!(output.contains("    let x = 5;"));
+        assert!(output.contains("❌ Not quite, it's a) Yes. Copy"));
+    }
+
+    #[test]
+    fn running_out_of_input_stops_the_quiz() {
+        let chapter = sample_chapter();
+        let result = take_quiz(&chapter, &mut "b\n".as_bytes(), &mut io::sink());
+        assert_eq!(result.unwrap(), None);
+    }
+
+    #[test]
+    fn record_save_and_reload() {
+        let path = std::env::temp_dir()
+            .join(format!("quiz_round_trip_{}", std::process::id()))
+            .join("quiz.json");
+
+        let mut scores = Scores::load(&path).unwrap();
+        assert!(scores.record("13", 2, 4));
+        assert!(!scores.record("13", 1, 4));
+        scores.save().unwrap();
+
+        let mut scores = Scores::load(&path).unwrap();
+        let expected = Score {
+            last: 1,
+            best: 2,
+            total: 4,
+        };
+        assert_eq!(scores.get("13"), Some(&expected));
+
+        // With a different number of questions, the old best is forgotten
+        assert!(scores.record("13", 1, 5));
+        assert_eq!(scores.get("13").map(|score| score.best), Some(1));
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+        assert!(parse_scores(r#"{"13": {"best": 1}}"#).is_err());
+    }
+}
@@ -0,0 +1,176 @@
+/// A binary search tree: smaller values go left, larger values go right.
+///
+/// The tree is not balanced, so inserting already-sorted data turns it into a
+/// long chain. That's fine for learning; real code would reach for `BTreeSet`.
+///
+/// ```
+/// use rust_tutorial::collections::BinaryTree;
+///
+/// let mut tree = BinaryTree::new();
+/// for n in [5, 3, 8, 1, 4] {
+///     tree.insert(n);
+/// }
+///
+/// assert!(tree.contains(&4));
+/// assert!(!tree.contains(&7));
+/// assert_eq!(tree.min(), Some(&1));
+/// assert_eq!(tree.max(), Some(&8));
+/// assert_eq!(tree.in_order(), vec![&1, &3, &4, &5, &8]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BinaryTree<T> {
+    root: Option<Box<Node<T>>>,
+    len: usize,
+}
+
+// Each child is an Option<Box<..>>: the Box gives the recursive type a known size
+#[derive(Debug, Clone)]
+struct Node<T> {
+    value: T,
+    left: Option<Box<Node<T>>>,
+    right: Option<Box<Node<T>>>,
+}
+
+impl<T: Ord> BinaryTree<T> {
+    /// Creates an empty tree.
+    pub fn new() -> Self {
+        BinaryTree { root: None, len: 0 }
+    }
+
+    /// Inserts `value`. Returns `false` (and drops `value`) if it was already in the tree.
+    pub fn insert(&mut self, value: T) -> bool {
+        let mut slot = &mut self.root;
+
+        while let Some(node) = slot {
+            if value < node.value {
+                slot = &mut node.left;
+            } else if value > node.value {
+                slot = &mut node.right;
+            } else {
+                return false;
+            }
+        }
+
+        *slot = Some(Box::new(Node {
+            value,
+            left: None,
+            right: None,
+        }));
+        self.len += 1;
+        true
+    }
+
+    /// Returns `true` if `value` is in the tree.
+    pub fn contains(&self, value: &T) -> bool {
+        let mut current = &self.root;
+
+        while let Some(node) = current {
+            if *value < node.value {
+                current = &node.left;
+            } else if *value > node.value {
+                current = &node.right;
+            } else {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// The smallest value (the left-most node).
+    pub fn min(&self) -> Option<&T> {
+        let mut node = self.root.as_ref()?;
+        while let Some(left) = &node.left {
+            node = left;
+        }
+        Some(&node.value)
+    }
+
+    /// The largest value (the right-most node).
+    pub fn max(&self) -> Option<&T> {
+        let mut node = self.root.as_ref()?;
+        while let Some(right) = &node.right {
+            node = right;
+        }
+        Some(&node.value)
+    }
+
+    /// Borrows every value in sorted order (left subtree, node, right subtree).
+    pub fn in_order(&self) -> Vec<&T> {
+        fn walk<'a, T>(node: &'a Option<Box<Node<T>>>, out: &mut Vec<&'a T>) {
+            if let Some(node) = node {
+                walk(&node.left, out);
+                out.push(&node.value);
+                walk(&node.right, out);
+            }
+        }
+
+        let mut out = Vec::with_capacity(self.len);
+        walk(&self.root, &mut out);
+        out
+    }
+
+    /// Number of values in the tree.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the tree holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<T: Ord> Default for BinaryTree<T> {
+    fn default() -> Self {
+        BinaryTree::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn in_order_is_sorted() {
+        let mut tree = BinaryTree::new();
+        for word in ["pear", "apple", "fig", "cherry"] {
+            tree.insert(word);
+        }
+
+        assert_eq!(tree.in_order(), vec![&"apple", &"cherry", &"fig", &"pear"]);
+        assert_eq!(tree.len(), 4);
+    }
+
+    #[test]
+    fn duplicates_are_rejected() {
+        let mut tree = BinaryTree::new();
+
+        assert!(tree.insert(10));
+        assert!(!tree.insert(10));
+        assert_eq!(tree.len(), 1);
+    }
+
+    #[test]
+    fn empty_tree() {
+        let tree: BinaryTree<i32> = BinaryTree::default();
+
+        assert!(tree.is_empty());
+        assert!(!tree.contains(&1));
+        assert_eq!(tree.min(), None);
+        assert_eq!(tree.max(), None);
+        assert!(tree.in_order().is_empty());
+    }
+
+    #[test]
+    fn sorted_input_still_works() {
+        // Degenerates into a chain, but every lookup must still succeed
+        let mut tree = BinaryTree::new();
+        for n in 0..100 {
+            tree.insert(n);
+        }
+
+        assert!((0..100).all(|n| tree.contains(&n)));
+        assert_eq!(tree.min(), Some(&0));
+        assert_eq!(tree.max(), Some(&99));
+    }
+}
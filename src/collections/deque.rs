@@ -0,0 +1,126 @@
+use std::collections::VecDeque;
+
+/// A double-ended queue: push and pop at both ends.
+///
+/// ```
+/// use rust_tutorial::collections::Deque;
+///
+/// let mut deque = Deque::new();
+/// deque.push_back(2);
+/// deque.push_front(1);
+/// deque.push_back(3);
+///
+/// assert_eq!(deque.front(), Some(&1));
+/// assert_eq!(deque.back(), Some(&3));
+/// assert_eq!(deque.pop_front(), Some(1));
+/// assert_eq!(deque.pop_back(), Some(3));
+/// assert_eq!(deque.len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Deque<T> {
+    items: VecDeque<T>,
+}
+
+impl<T> Deque<T> {
+    /// Creates an empty deque.
+    pub fn new() -> Self {
+        Deque {
+            items: VecDeque::new(),
+        }
+    }
+
+    /// Adds `item` at the front.
+    pub fn push_front(&mut self, item: T) {
+        self.items.push_front(item);
+    }
+
+    /// Adds `item` at the back.
+    pub fn push_back(&mut self, item: T) {
+        self.items.push_back(item);
+    }
+
+    /// Removes and returns the front item, or `None` if the deque is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.items.pop_front()
+    }
+
+    /// Removes and returns the back item, or `None` if the deque is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.items.pop_back()
+    }
+
+    /// Borrows the front item.
+    pub fn front(&self) -> Option<&T> {
+        self.items.front()
+    }
+
+    /// Borrows the back item.
+    pub fn back(&self) -> Option<&T> {
+        self.items.back()
+    }
+
+    /// Number of items in the deque.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if the deque holds no items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+impl<T> Default for Deque<T> {
+    fn default() -> Self {
+        Deque::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn works_as_a_stack_from_either_end() {
+        let mut deque = Deque::new();
+        deque.push_front(1);
+        deque.push_front(2);
+        assert_eq!(deque.pop_front(), Some(2));
+
+        deque.push_back(3);
+        deque.push_back(4);
+        assert_eq!(deque.pop_back(), Some(4));
+        assert_eq!(deque.len(), 2);
+    }
+
+    #[test]
+    fn works_as_a_queue() {
+        let mut deque = Deque::new();
+        deque.push_back("a");
+        deque.push_back("b");
+
+        assert_eq!(deque.pop_front(), Some("a"));
+        assert_eq!(deque.pop_front(), Some("b"));
+        assert!(deque.is_empty());
+    }
+
+    #[test]
+    fn empty_deque_returns_none() {
+        let mut deque: Deque<u8> = Deque::default();
+
+        assert_eq!(deque.front(), None);
+        assert_eq!(deque.back(), None);
+        assert_eq!(deque.pop_front(), None);
+        assert_eq!(deque.pop_back(), None);
+    }
+
+    #[test]
+    fn single_item_is_both_front_and_back() {
+        let mut deque = Deque::new();
+        deque.push_back(7);
+
+        assert_eq!(deque.front(), deque.back());
+        assert_eq!(deque.pop_back(), Some(7));
+        assert_eq!(deque.pop_front(), None);
+    }
+}
@@ -0,0 +1,11 @@
+//! Small generic data structures, written the "textbook" way so they are easy to read.
+
+mod binary_tree;
+mod deque;
+mod queue;
+mod stack;
+
+pub use binary_tree::BinaryTree;
+pub use deque::Deque;
+pub use queue::Queue;
+pub use stack::Stack;
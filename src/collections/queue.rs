@@ -0,0 +1,101 @@
+use std::collections::VecDeque;
+
+/// A first-in, first-out queue.
+///
+/// ```
+/// use rust_tutorial::collections::Queue;
+///
+/// let mut queue = Queue::new();
+/// queue.enqueue("first");
+/// queue.enqueue("second");
+///
+/// assert_eq!(queue.peek(), Some(&"first"));
+/// assert_eq!(queue.dequeue(), Some("first"));
+/// assert_eq!(queue.dequeue(), Some("second"));
+/// assert_eq!(queue.dequeue(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Queue<T> {
+    // A VecDeque makes removing from the front cheap; Vec::remove(0) would shift every item
+    items: VecDeque<T>,
+}
+
+impl<T> Queue<T> {
+    /// Creates an empty queue.
+    pub fn new() -> Self {
+        Queue {
+            items: VecDeque::new(),
+        }
+    }
+
+    /// Adds `item` to the back of the queue.
+    pub fn enqueue(&mut self, item: T) {
+        self.items.push_back(item);
+    }
+
+    /// Removes and returns the item at the front, or `None` if the queue is empty.
+    pub fn dequeue(&mut self) -> Option<T> {
+        self.items.pop_front()
+    }
+
+    /// Borrows the item at the front (the next one out) without removing it.
+    pub fn peek(&self) -> Option<&T> {
+        self.items.front()
+    }
+
+    /// Number of items in the queue.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if the queue holds no items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+impl<T> Default for Queue<T> {
+    fn default() -> Self {
+        Queue::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dequeues_in_enqueue_order() {
+        let mut queue = Queue::new();
+        for n in 1..=3 {
+            queue.enqueue(n);
+        }
+
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.dequeue(), Some(3));
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn empty_queue_returns_none() {
+        let mut queue: Queue<String> = Queue::default();
+
+        assert_eq!(queue.peek(), None);
+        assert_eq!(queue.dequeue(), None);
+    }
+
+    #[test]
+    fn interleaved_enqueue_and_dequeue() {
+        let mut queue = Queue::new();
+        queue.enqueue('a');
+        queue.enqueue('b');
+        assert_eq!(queue.dequeue(), Some('a'));
+
+        queue.enqueue('c');
+        assert_eq!(queue.peek(), Some(&'b'));
+        assert_eq!(queue.dequeue(), Some('b'));
+        assert_eq!(queue.dequeue(), Some('c'));
+    }
+}
@@ -0,0 +1,265 @@
+/// A last-in, first-out stack backed by a `Vec<T>`.
+///
+/// ```
+/// use rust_tutorial::collections::Stack;
+///
+/// let mut stack = Stack::new();
+/// stack.push(1);
+/// stack.push(2);
+///
+/// assert_eq!(stack.peek(), Some(&2));
+/// assert_eq!(stack.pop(), Some(2));
+/// assert_eq!(stack.pop(), Some(1));
+/// assert_eq!(stack.pop(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Stack<T> {
+    items: Vec<T>,
+}
+
+impl<T> Stack<T> {
+    /// Creates an empty stack.
+    pub fn new() -> Self {
+        Stack { items: Vec::new() }
+    }
+
+    /// Creates an empty stack with room for `capacity` items.
+    ///
+    /// Pushing up to that many items never reallocates. A `Stack::new()` instead
+    /// grows as it goes, copying everything to a bigger buffer each time it fills up.
+    ///
+    /// ```
+    /// use rust_tutorial::collections::Stack;
+    ///
+    /// let mut stack = Stack::with_capacity(10);
+    /// assert!(stack.is_empty());
+    /// assert!(stack.capacity() >= 10);
+    ///
+    /// stack.push('a');
+    /// assert!(stack.capacity() >= 10);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Stack {
+            items: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// How many items the stack can hold before it has to reallocate.
+    pub fn capacity(&self) -> usize {
+        self.items.capacity()
+    }
+
+    /// Puts `item` on top of the stack.
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+    }
+
+    /// Removes and returns the top item, or `None` if the stack is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+
+    /// Borrows the top item without removing it.
+    pub fn peek(&self) -> Option<&T> {
+        self.items.last()
+    }
+
+    /// Mutably borrows the top item without removing it.
+    ///
+    /// ```
+    /// use rust_tutorial::collections::Stack;
+    ///
+    /// let mut stack = Stack::new();
+    /// stack.push(String::from("hello"));
+    /// if let Some(top) = stack.peek_mut() {
+    ///     top.push_str(", world");
+    /// }
+    /// assert_eq!(stack.peek().map(String::as_str), Some("hello, world"));
+    /// ```
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.items.last_mut()
+    }
+
+    /// Number of items on the stack.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if the stack holds no items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Borrows every item from the top of the stack down, without removing anything.
+    ///
+    /// ```
+    /// use rust_tutorial::collections::Stack;
+    ///
+    /// let mut stack = Stack::new();
+    /// stack.push(1);
+    /// stack.push(2);
+    /// stack.push(3);
+    ///
+    /// let top_down: Vec<&i32> = stack.iter().collect();
+    /// assert_eq!(top_down, vec![&3, &2, &1]);
+    /// assert_eq!(stack.len(), 3);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { items: &self.items }
+    }
+}
+
+impl<T> Default for Stack<T> {
+    fn default() -> Self {
+        Stack::new()
+    }
+}
+
+/// Borrowing iterator over a [`Stack`], top item first. Created by [`Stack::iter`].
+pub struct Iter<'a, T> {
+    // The part of the stack we haven't visited yet; the top is the end of the slice
+    items: &'a [T],
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let (top, rest) = self.items.split_last()?;
+        self.items = rest;
+        Some(top)
+    }
+}
+
+/// Owning iterator that pops every item off a [`Stack`].
+///
+/// ```
+/// use rust_tutorial::collections::Stack;
+///
+/// let mut stack = Stack::new();
+/// stack.push("a");
+/// stack.push("b");
+///
+/// let popped: Vec<&str> = stack.into_iter().collect();
+/// assert_eq!(popped, vec!["b", "a"]);
+/// ```
+pub struct IntoIter<T> {
+    stack: Stack<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.stack.pop()
+    }
+}
+
+impl<T> IntoIterator for Stack<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { stack: self }
+    }
+}
+
+// Lets `for item in &stack` work
+impl<'a, T> IntoIterator for &'a Stack<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pops_in_reverse_push_order() {
+        let mut stack = Stack::new();
+        for n in 1..=3 {
+            stack.push(n);
+        }
+
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.pop(), Some(1));
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn empty_stack_returns_none() {
+        let mut stack: Stack<i32> = Stack::default();
+
+        assert_eq!(stack.peek(), None);
+        assert_eq!(stack.pop(), None);
+        assert_eq!(stack.len(), 0);
+    }
+
+    #[test]
+    fn peek_does_not_remove() {
+        let mut stack = Stack::new();
+        stack.push("top");
+
+        assert_eq!(stack.peek(), Some(&"top"));
+        assert_eq!(stack.len(), 1);
+
+        *stack.peek_mut().unwrap() = "changed";
+        assert_eq!(stack.pop(), Some("changed"));
+    }
+
+    #[test]
+    fn with_capacity_does_not_reallocate_until_full() {
+        let mut stack = Stack::with_capacity(4);
+        let capacity = stack.capacity();
+        assert!(capacity >= 4);
+
+        for n in 0..capacity {
+            stack.push(n);
+        }
+        assert_eq!(stack.capacity(), capacity);
+
+        stack.push(capacity);
+        assert!(stack.capacity() > capacity);
+    }
+
+    #[test]
+    fn iter_goes_top_down_and_leaves_stack_alone() {
+        let mut stack = Stack::new();
+        for n in 1..=3 {
+            stack.push(n);
+        }
+
+        let mut seen = Vec::new();
+        for n in &stack {
+            seen.push(*n);
+        }
+
+        assert_eq!(seen, vec![3, 2, 1]);
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.iter().sum::<i32>(), 6);
+    }
+
+    #[test]
+    fn into_iter_pops_everything() {
+        let mut stack = Stack::new();
+        stack.push(String::from("bottom"));
+        stack.push(String::from("top"));
+
+        let items: Vec<String> = stack.into_iter().collect();
+        assert_eq!(items, vec!["top", "bottom"]);
+    }
+
+    #[test]
+    fn iterators_on_empty_stack() {
+        let stack: Stack<u8> = Stack::new();
+
+        assert_eq!(stack.iter().next(), None);
+        assert_eq!(stack.into_iter().next(), None);
+    }
+}
@@ -0,0 +1,160 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::SystemTime;
+
+// The cargo binary that launched us (set by `cargo run`), or whatever is on PATH
+fn cargo() -> String {
+    std::env::var("CARGO").unwrap_or_else(|_| String::from("cargo"))
+}
+
+// One exercise file under examples/
+// e.g. "examples/10_1_Rust_struct.rs" -> id "10_1", name "10_1_Rust_struct"
+pub struct Exercise {
+    pub id: String,
+    pub name: String,
+    pub path: PathBuf,
+}
+
+// What happened when we compiled and ran an exercise
+pub struct Outcome {
+    pub success: bool,
+    pub output: String,
+}
+
+impl Exercise {
+    // Find every exercise in `dir`, sorted by chapter number
+    pub fn discover(dir: &Path) -> io::Result<Vec<Exercise>> {
+        let mut exercises = Vec::new();
+
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if let Some(exercise) = Exercise::from_path(&path) {
+                exercises.push(exercise);
+            }
+        }
+
+        exercises.sort_by_key(|exercise| exercise.order());
+        Ok(exercises)
+    }
+
+    // Single files (`13_foo.rs`) and directories with a main.rs (`22_foo/`) both count.
+    // Anything else (like the compiled binaries next to the sources) is skipped.
+    fn from_path(path: &Path) -> Option<Exercise> {
+        let is_file_exercise = path.is_file() && path.extension()? == "rs";
+        let is_dir_exercise = path.is_dir() && path.join("main.rs").is_file();
+        if !is_file_exercise && !is_dir_exercise {
+            return None;
+        }
+
+        let name = path.file_stem()?.to_str()?.to_string();
+        let id = name
+            .split('_')
+            .take_while(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+            .collect::<Vec<_>>()
+            .join("_");
+        if id.is_empty() {
+            return None;
+        }
+
+        Some(Exercise {
+            id,
+            name,
+            path: path.to_path_buf(),
+        })
+    }
+
+    // "10_1" -> [10, 1], so 10_1 sorts before 10_2 and 9 sorts before 10
+    fn order(&self) -> Vec<u32> {
+        self.id.split('_').filter_map(|n| n.parse().ok()).collect()
+    }
+
+    // The leading number: "10_2" -> "10". Exercises sharing one form a topic.
+    pub fn chapter(&self) -> &str {
+        self.id.split('_').next().unwrap_or(&self.id)
+    }
+
+    // `13`, `10_1`, `10.1` and the full file name all select an exercise. Numbers are
+    // compared as numbers, so `6` finds `06`.
+    pub fn matches(&self, query: &str) -> bool {
+        let numbers: Option<Vec<u32>> = query.split(['_', '.']).map(|n| n.parse().ok()).collect();
+        match numbers {
+            Some(numbers) => numbers == self.order(),
+            None => self.name == query,
+        }
+    }
+
+    // Newest modification time of the exercise (any file inside it, for directories)
+    pub fn modified(&self) -> io::Result<SystemTime> {
+        newest_mtime(&self.path)
+    }
+
+    // Compile and run, capturing output. Used by `verify`.
+    pub fn check(&self) -> io::Result<Outcome> {
+        let output = self.cargo_run().stdin(Stdio::null()).output()?;
+
+        let mut text = String::from_utf8_lossy(&output.stdout).into_owned();
+        text.push_str(&String::from_utf8_lossy(&output.stderr));
+
+        Ok(Outcome {
+            success: output.status.success(),
+            output: text,
+        })
+    }
+
+    // Compile and run with the terminal attached, so interactive exercises work. Used by `run`.
+    pub fn run(&self) -> io::Result<bool> {
+        Ok(self.cargo_run().status()?.success())
+    }
+
+    fn cargo_run(&self) -> Command {
+        let mut command = Command::new(cargo());
+        command
+            .args(["run", "--quiet", "--example", &self.name])
+            .current_dir(env!("CARGO_MANIFEST_DIR"));
+        command
+    }
+}
+
+fn newest_mtime(path: &Path) -> io::Result<SystemTime> {
+    let mut newest = fs::metadata(path)?.modified()?;
+
+    if path.is_dir() {
+        for entry in fs::read_dir(path)? {
+            let time = newest_mtime(&entry?.path())?;
+            if time > newest {
+                newest = time;
+            }
+        }
+    }
+    Ok(newest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exercise(id: &str, name: &str) -> Exercise {
+        Exercise {
+            id: id.to_string(),
+            name: name.to_string(),
+            path: PathBuf::from(name),
+        }
+    }
+
+    #[test]
+    fn matches_by_number_or_name() {
+        let strings = exercise("06", "06_rust_strings_vs_str_slices");
+        assert!(strings.matches("06"));
+        assert!(strings.matches("6"));
+        assert!(strings.matches("06_rust_strings_vs_str_slices"));
+        assert!(!strings.matches("16"));
+        assert!(!strings.matches("06_2"));
+
+        let structs = exercise("10_1", "10_1_Rust_struct");
+        assert!(structs.matches("10_1"));
+        assert!(structs.matches("10.1"));
+        assert!(!structs.matches("10"));
+    }
+}
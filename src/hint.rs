@@ -0,0 +1,103 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+// Hints for one exercise live in hints/<exercise name>.md. Every `## ` heading
+// starts the next level, going from a gentle nudge down to the full answer:
+//
+//   ## Level 1: who owns it?
+//   When you pass a String to a function, ...
+//
+//   ## Level 2: the signatures
+//   ...
+//
+// Returns Ok(None) when the exercise has no hints file yet.
+pub fn load(dir: &Path, exercise_name: &str) -> io::Result<Option<Vec<String>>> {
+    match fs::read_to_string(dir.join(format!("{}.md", exercise_name))) {
+        Ok(text) => Ok(Some(levels(&text))),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+// Split the file into levels. Anything before the first heading is ignored,
+// so the file can start with a note for whoever writes the hints.
+fn levels(text: &str) -> Vec<String> {
+    let mut levels: Vec<String> = Vec::new();
+
+    for line in text.lines() {
+        if line.starts_with("## ") {
+            levels.push(String::new());
+        }
+        if let Some(level) = levels.last_mut() {
+            level.push_str(line);
+            level.push('\n');
+        }
+    }
+
+    levels
+        .iter()
+        .map(|level| level.trim_end().to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercise::Exercise;
+
+    #[test]
+    fn each_heading_starts_a_level() {
+        let text = "\
+A note for hint writers
+
+## Level 1: nudge
+Think about it.
+
+## Level 2: answer
+let x = 5;
+";
+        assert_eq!(
+            levels(text),
+            vec![
+                "## Level 1: nudge\nThink about it.",
+                "## Level 2: answer\nlet x = 5;"
+            ]
+        );
+    }
+
+    #[test]
+    fn no_headings_means_no_levels() {
+        assert!(levels("").is_empty());
+        assert!(levels("just some text\n# not a level").is_empty());
+    }
+
+    #[test]
+    fn every_exercise_with_todos_has_hints() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+        for exercise in Exercise::discover(&root.join("examples")).unwrap() {
+            let source = if exercise.path.is_dir() {
+                exercise.path.join("main.rs")
+            } else {
+                exercise.path.clone()
+            };
+            if !fs::read_to_string(&source).unwrap().contains("// TODO") {
+                continue;
+            }
+
+            let hints = load(&root.join("hints"), &exercise.name).unwrap();
+            assert!(
+                hints.is_some_and(|levels| !levels.is_empty()),
+                "{} has TODOs but no hints",
+                exercise.name
+            );
+        }
+    }
+
+    #[test]
+    fn missing_file_is_not_an_error() {
+        let hints = load(Path::new("does/not/exist"), "13_anything").unwrap();
+        assert!(hints.is_none());
+    }
+}
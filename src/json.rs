@@ -0,0 +1,528 @@
+//! A small JSON reader and writer, enough for the tutorial's save files.
+//!
+//! Real projects would use `serde_json`; this one is written by hand so the
+//! projects can persist data with nothing but `std`, and so you can read how a
+//! recursive-descent parser turns text into an enum.
+//!
+//! ```
+//! use rust_tutorial::json::{self, Value};
+//!
+//! let value = json::parse(r#"{"name": "Ferris", "age": 8, "tags": ["crab"]}"#).unwrap();
+//! assert_eq!(value.get("name").and_then(Value::as_str), Some("Ferris"));
+//! assert_eq!(value.get("age").and_then(Value::as_f64), Some(8.0));
+//!
+//! let text = value.to_string();
+//! assert_eq!(text, r#"{"age":8,"name":"Ferris","tags":["crab"]}"#);
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Any JSON value.
+///
+/// Objects use a `BTreeMap`, so keys always come out sorted and output is stable.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    Object(BTreeMap<String, Value>),
+}
+
+impl Value {
+    /// Looks up `key` if this is an object.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(map) => map.get(key),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Value::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    pub fn as_object(&self) -> Option<&BTreeMap<String, Value>> {
+        match self {
+            Value::Object(map) => Some(map),
+            _ => None,
+        }
+    }
+
+    /// Formats the value over several lines with two-space indentation.
+    ///
+    /// ```
+    /// use rust_tutorial::json::Value;
+    ///
+    /// let value = Value::Array(vec![Value::Bool(true), Value::Null]);
+    /// assert_eq!(value.to_pretty_string(), "[\n  true,\n  null\n]");
+    /// ```
+    pub fn to_pretty_string(&self) -> String {
+        let mut out = String::new();
+        self.write(&mut out, Some(0));
+        out
+    }
+
+    // `indent` is None for compact output, or the current nesting level for pretty output
+    fn write(&self, out: &mut String, indent: Option<usize>) {
+        match self {
+            Value::Null => out.push_str("null"),
+            Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+            Value::Number(n) => write_number(out, *n),
+            Value::String(s) => write_string(out, s),
+            Value::Array(items) => {
+                write_list(out, '[', ']', indent, items.iter(), |out, item, indent| {
+                    item.write(out, indent)
+                });
+            }
+            Value::Object(map) => {
+                write_list(
+                    out,
+                    '{',
+                    '}',
+                    indent,
+                    map.iter(),
+                    |out, (key, value), indent| {
+                        write_string(out, key);
+                        out.push_str(if indent.is_some() { ": " } else { ":" });
+                        value.write(out, indent);
+                    },
+                );
+            }
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    /// Compact, single-line JSON.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut out = String::new();
+        self.write(&mut out, None);
+        f.write_str(&out)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(s: &str) -> Self {
+        Value::String(s.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Self {
+        Value::String(s)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(n: f64) -> Self {
+        Value::Number(n)
+    }
+}
+
+impl From<u32> for Value {
+    fn from(n: u32) -> Self {
+        Value::Number(n as f64)
+    }
+}
+
+impl From<bool> for Value {
+    fn from(b: bool) -> Self {
+        Value::Bool(b)
+    }
+}
+
+fn write_number(out: &mut String, n: f64) {
+    if !n.is_finite() {
+        // JSON has no NaN or infinity
+        out.push_str("null");
+    } else if n.fract() == 0.0 && n.abs() < 1e15 {
+        out.push_str(&format!("{}", n as i64));
+    } else {
+        out.push_str(&n.to_string());
+    }
+}
+
+fn write_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+fn write_list<I, T>(
+    out: &mut String,
+    open: char,
+    close: char,
+    indent: Option<usize>,
+    items: I,
+    mut write_item: impl FnMut(&mut String, T, Option<usize>),
+) where
+    I: ExactSizeIterator<Item = T>,
+{
+    out.push(open);
+    if items.len() == 0 {
+        out.push(close);
+        return;
+    }
+
+    let inner = indent.map(|level| level + 1);
+    for (i, item) in items.enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        if let Some(level) = inner {
+            out.push('\n');
+            out.push_str(&"  ".repeat(level));
+        }
+        write_item(out, item, inner);
+    }
+    if let Some(level) = indent {
+        out.push('\n');
+        out.push_str(&"  ".repeat(level));
+    }
+    out.push(close);
+}
+
+/// Why some text isn't valid JSON, and where (a byte offset) the problem starts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub message: String,
+    pub offset: usize,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid JSON at byte {}: {}", self.offset, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses a complete JSON document.
+///
+/// ```
+/// use rust_tutorial::json;
+///
+/// assert!(json::parse("[1, 2, 3]").is_ok());
+/// assert!(json::parse("[1, 2,").is_err());
+/// ```
+pub fn parse(text: &str) -> Result<Value, ParseError> {
+    let mut parser = Parser { text, pos: 0 };
+
+    let value = parser.value(0)?;
+    parser.skip_whitespace();
+    if parser.pos < text.len() {
+        return Err(parser.error("unexpected text after the value"));
+    }
+    Ok(value)
+}
+
+// Deeper nesting than this is an error rather than a stack overflow. Each level of
+// `[[[...` is one more recursive call, and a damaged save file could have thousands.
+const MAX_DEPTH: usize = 128;
+
+// Recursive descent: one method per kind of value, each consuming its own text.
+// `depth` counts the arrays and objects we're already inside.
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: &str) -> ParseError {
+        ParseError {
+            message: message.to_string(),
+            offset: self.pos,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.text[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            if !matches!(c, ' ' | '\t' | '\n' | '\r') {
+                break;
+            }
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        if self.peek() == Some(expected) {
+            self.pos += expected.len_utf8();
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", expected)))
+        }
+    }
+
+    fn keyword(&mut self, word: &str, value: Value) -> Result<Value, ParseError> {
+        if self.text[self.pos..].starts_with(word) {
+            self.pos += word.len();
+            Ok(value)
+        } else {
+            Err(self.error("unknown keyword"))
+        }
+    }
+
+    fn value(&mut self, depth: usize) -> Result<Value, ParseError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('n') => self.keyword("null", Value::Null),
+            Some('t') => self.keyword("true", Value::Bool(true)),
+            Some('f') => self.keyword("false", Value::Bool(false)),
+            Some('"') => Ok(Value::String(self.string()?)),
+            Some('[' | '{') if depth >= MAX_DEPTH => Err(self.error("nested too deeply")),
+            Some('[') => self.array(depth + 1),
+            Some('{') => self.object(depth + 1),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.number(),
+            Some(_) => Err(self.error("expected a value")),
+            None => Err(self.error("unexpected end of input")),
+        }
+    }
+
+    fn number(&mut self) -> Result<Value, ParseError> {
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if !(c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E')) {
+                break;
+            }
+            self.pos += 1;
+        }
+
+        self.text[start..self.pos]
+            .parse()
+            .map(Value::Number)
+            .map_err(|_| ParseError {
+                message: String::from("invalid number"),
+                offset: start,
+            })
+    }
+
+    fn string(&mut self) -> Result<String, ParseError> {
+        self.expect('"')?;
+        let mut out = String::new();
+
+        loop {
+            match self.bump() {
+                Some('"') => return Ok(out),
+                Some('\\') => out.push(self.escape()?),
+                Some(c) => out.push(c),
+                None => return Err(self.error("unterminated string")),
+            }
+        }
+    }
+
+    fn escape(&mut self) -> Result<char, ParseError> {
+        match self.bump() {
+            Some('"') => Ok('"'),
+            Some('\\') => Ok('\\'),
+            Some('/') => Ok('/'),
+            Some('b') => Ok('\u{8}'),
+            Some('f') => Ok('\u{c}'),
+            Some('n') => Ok('\n'),
+            Some('r') => Ok('\r'),
+            Some('t') => Ok('\t'),
+            Some('u') => {
+                let high = self.hex4()?;
+                // Characters outside the BMP are written as a surrogate pair: 🦀
+                if (0xD800..0xDC00).contains(&high) {
+                    self.expect('\\')?;
+                    self.expect('u')?;
+                    let low = self.hex4()?;
+                    if !(0xDC00..0xE000).contains(&low) {
+                        return Err(self.error("invalid surrogate pair"));
+                    }
+                    let code = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+                    char::from_u32(code).ok_or_else(|| self.error("invalid surrogate pair"))
+                } else {
+                    char::from_u32(high).ok_or_else(|| self.error("invalid \\u escape"))
+                }
+            }
+            _ => Err(self.error("invalid escape")),
+        }
+    }
+
+    fn hex4(&mut self) -> Result<u32, ParseError> {
+        let digits = self
+            .text
+            .get(self.pos..self.pos + 4)
+            .ok_or_else(|| self.error("expected four hex digits"))?;
+        let code = u32::from_str_radix(digits, 16).map_err(|_| self.error("invalid hex digits"))?;
+        self.pos += 4;
+        Ok(code)
+    }
+
+    fn array(&mut self, depth: usize) -> Result<Value, ParseError> {
+        self.expect('[')?;
+        let mut items = Vec::new();
+
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.pos += 1;
+            return Ok(Value::Array(items));
+        }
+
+        loop {
+            items.push(self.value(depth)?);
+            self.skip_whitespace();
+            match self.bump() {
+                Some(',') => continue,
+                Some(']') => return Ok(Value::Array(items)),
+                _ => return Err(self.error("expected ',' or ']'")),
+            }
+        }
+    }
+
+    fn object(&mut self, depth: usize) -> Result<Value, ParseError> {
+        self.expect('{')?;
+        let mut map = BTreeMap::new();
+
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.pos += 1;
+            return Ok(Value::Object(map));
+        }
+
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            let value = self.value(depth)?;
+            map.insert(key, value);
+
+            self.skip_whitespace();
+            match self.bump() {
+                Some(',') => continue,
+                Some('}') => return Ok(Value::Object(map)),
+                _ => return Err(self.error("expected ',' or '}'")),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_scalars() {
+        assert_eq!(parse("null"), Ok(Value::Null));
+        assert_eq!(parse(" true "), Ok(Value::Bool(true)));
+        assert_eq!(parse("-12.5e1"), Ok(Value::Number(-125.0)));
+        assert_eq!(parse(r#""hi""#), Ok(Value::from("hi")));
+    }
+
+    #[test]
+    fn parses_nested_structures() {
+        let value = parse(r#"{"list": [1, {"deep": null}], "empty": {}}"#).unwrap();
+
+        let list = value.get("list").and_then(Value::as_array).unwrap();
+        assert_eq!(list[0], Value::Number(1.0));
+        assert_eq!(list[1].get("deep"), Some(&Value::Null));
+        assert_eq!(
+            value
+                .get("empty")
+                .and_then(Value::as_object)
+                .map(|m| m.len()),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn string_escapes_round_trip() {
+        let original = Value::from("quote \" slash \\ newline \n tab \t crab 🦀 é");
+        let text = original.to_string();
+
+        assert_eq!(parse(&text), Ok(original));
+        assert_eq!(parse(r#""\u00e9\ud83e\udd80""#), Ok(Value::from("é🦀")));
+        assert!(parse(r#""\ud83e\u0041""#).is_err());
+    }
+
+    #[test]
+    fn pretty_output_parses_back() {
+        let value = parse(r#"{"b": [1, 2], "a": {"x": true}, "c": []}"#).unwrap();
+        let pretty = value.to_pretty_string();
+
+        assert!(pretty.contains("\n  \"a\": {\n    \"x\": true\n  },"));
+        assert_eq!(parse(&pretty), Ok(value));
+    }
+
+    #[test]
+    fn numbers_print_without_trailing_zeros() {
+        assert_eq!(Value::from(3u32).to_string(), "3");
+        assert_eq!(Value::from(2.5).to_string(), "2.5");
+        assert_eq!(Value::from(f64::NAN).to_string(), "null");
+    }
+
+    #[test]
+    fn reports_errors_with_offsets() {
+        let err = parse("[1, 2").unwrap_err();
+        assert_eq!(err.offset, 5);
+
+        assert!(parse("").is_err());
+        assert!(parse("nul").is_err());
+        assert!(parse(r#"{"a" 1}"#).is_err());
+        assert!(parse(r#""never ends"#).is_err());
+        assert!(parse("[1] extra").is_err());
+        assert!(parse("1.2.3").is_err());
+    }
+
+    #[test]
+    fn deep_nesting_is_an_error_not_a_crash() {
+        let nested = |depth: usize| "[".repeat(depth) + &"]".repeat(depth);
+        assert!(parse(&nested(MAX_DEPTH)).is_ok());
+
+        let err = parse(&nested(MAX_DEPTH + 1)).unwrap_err();
+        assert_eq!(err.message, "nested too deeply");
+        assert_eq!(err.offset, MAX_DEPTH);
+
+        // Far too deep to recurse into, and still just an error
+        assert!(parse(&"[{\"a\":".repeat(100_000)).is_err());
+    }
+}
@@ -0,0 +1,10 @@
+//! Shared code for the tutorial chapters.
+//!
+//! The examples under `examples/` can pull these in with
+//! `use rust_tutorial::collections::Stack;` instead of re-declaring them inline.
+
+pub mod collections;
+pub mod json;
+pub mod save_file;
+pub mod shapes;
+pub mod strutil;
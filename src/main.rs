@@ -1,3 +1,389 @@
-fn main() {
-    println!("Hello, world!");
+// 🦀 Tutor: compile, run and verify the exercises in examples/
+//
+//   cargo run -- list               show every exercise
+//   cargo run -- verify             check that every exercise compiles and runs
+//   cargo run -- verify --watch     ...then re-check each file when you save it
+//   cargo run -- run 13             run one exercise (13, 10_1 and 10.1 all work)
+//   cargo run -- run 13 --watch     ...and run it again on every save
+//   cargo run -- done 13            finished one? check it passes and mark it done
+//   cargo run -- status             see which exercises you've marked done
+//   cargo run -- reset 13           mark one exercise as not done again
+//   cargo run -- hint 13            stuck? get a nudge (from hints/)
+//   cargo run -- hint 13 --level 2  ...or more help, up to the full answer
+//
+// `done` is the only thing that marks an exercise finished: every file compiles and runs
+// before you've typed anything, so passing `verify` says nothing about your progress.
+// Progress is kept in .tutor/progress.json.
+// For multiple-choice questions on a chapter instead, try `cargo run --bin quiz`.
+
+mod exercise;
+mod hint;
+mod progress;
+
+use exercise::Exercise;
+use progress::Progress;
+use std::path::Path;
+use std::process::ExitCode;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+const USAGE: &str = "\
+Usage:
+  cargo run -- list
+  cargo run -- verify [--watch]
+  cargo run -- run <exercise> [--watch]
+  cargo run -- done <exercise>
+  cargo run -- status
+  cargo run -- reset <exercise>
+  cargo run -- hint <exercise> [--level <n>]";
+
+// How often --watch looks at the files again
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+enum Command {
+    List,
+    Verify { watch: bool },
+    Run { id: String, watch: bool },
+    Done { id: String },
+    Status,
+    Reset { id: String },
+    Hint { id: String, level: usize },
+    Help,
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let mut watch = false;
+    let mut level = 1;
+    let mut words = Vec::new();
+
+    let mut rest = args.iter();
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--watch" => watch = true,
+            "--level" => {
+                level = match rest.next().map(|n| n.parse()) {
+                    Some(Ok(n)) if n > 0 => n,
+                    _ => return Err(String::from("`--level` needs a number from 1 up")),
+                }
+            }
+            flag if flag.starts_with("--") => return Err(format!("Unknown option `{}`", flag)),
+            _ => words.push(arg),
+        }
+    }
+    let mut words = words.into_iter();
+
+    match words.next().map(String::as_str) {
+        None | Some("help") => Ok(Command::Help),
+        Some("list") => Ok(Command::List),
+        Some("verify") => Ok(Command::Verify { watch }),
+        Some("run") => match words.next() {
+            Some(id) => Ok(Command::Run {
+                id: id.clone(),
+                watch,
+            }),
+            None => Err(String::from(
+                "`run` needs an exercise, e.g. `cargo run -- run 13`",
+            )),
+        },
+        Some("done") => match words.next() {
+            Some(id) => Ok(Command::Done { id: id.clone() }),
+            None => Err(String::from(
+                "`done` needs an exercise, e.g. `cargo run -- done 13`",
+            )),
+        },
+        Some("status") => Ok(Command::Status),
+        Some("reset") => match words.next() {
+            Some(id) => Ok(Command::Reset { id: id.clone() }),
+            None => Err(String::from(
+                "`reset` needs an exercise, e.g. `cargo run -- reset 13`",
+            )),
+        },
+        Some("hint") => match words.next() {
+            Some(id) => Ok(Command::Hint {
+                id: id.clone(),
+                level,
+            }),
+            None => Err(String::from(
+                "`hint` needs an exercise, e.g. `cargo run -- hint 13`",
+            )),
+        },
+        Some(other) => Err(format!("Unknown command `{}`", other)),
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let command = match parse_args(&args) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let examples_dir = root.join("examples");
+    let exercises = match Exercise::discover(&examples_dir) {
+        Ok(exercises) => exercises,
+        Err(err) => {
+            eprintln!("Could not read {}: {}", examples_dir.display(), err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let ok = match command {
+        Command::Help => {
+            println!("{}", USAGE);
+            true
+        }
+        Command::List => {
+            for exercise in &exercises {
+                println!("{:>5}  {}", exercise.id, exercise.name);
+            }
+            true
+        }
+        Command::Verify { watch } => {
+            let ok = verify(&exercises);
+            if watch {
+                watch_files(&exercises, |exercise| {
+                    check(exercise);
+                });
+            }
+            ok
+        }
+        Command::Run { id, watch } => match find(&exercises, &id) {
+            Some(exercise) => {
+                let ok = run(exercise);
+                if watch {
+                    watch_files(std::slice::from_ref(exercise), |exercise| {
+                        run(exercise);
+                    });
+                }
+                ok
+            }
+            None => false,
+        },
+        Command::Done { id } => match (find(&exercises, &id), load_progress(root)) {
+            (Some(exercise), Some(mut progress)) => done(exercise, &mut progress),
+            _ => false,
+        },
+        Command::Status => match load_progress(root) {
+            Some(progress) => {
+                status(&exercises, &progress);
+                true
+            }
+            None => false,
+        },
+        Command::Reset { id } => match (find(&exercises, &id), load_progress(root)) {
+            (Some(exercise), Some(mut progress)) => {
+                if progress.reset(&exercise.name) {
+                    println!("↩️  {} is no longer marked as done", exercise.name);
+                    save(&progress);
+                } else {
+                    println!("{} wasn't marked as done", exercise.name);
+                }
+                true
+            }
+            _ => false,
+        },
+        Command::Hint { id, level } => match find(&exercises, &id) {
+            Some(exercise) => show_hints(&root.join("hints"), exercise, level),
+            None => false,
+        },
+    };
+
+    if ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn find<'a>(exercises: &'a [Exercise], id: &str) -> Option<&'a Exercise> {
+    let found = exercises.iter().find(|exercise| exercise.matches(id));
+    if found.is_none() {
+        eprintln!("No exercise matches `{}`. Try `cargo run -- list`.", id);
+    }
+    found
+}
+
+// Only the commands that show or change progress read the file, so a damaged
+// progress.json doesn't get in the way of `list`, `hint` or `verify`
+fn load_progress(root: &Path) -> Option<Progress> {
+    match Progress::load(&root.join(".tutor").join("progress.json")) {
+        Ok(progress) => Some(progress),
+        Err(err) => {
+            eprintln!("Could not read your progress: {}", err);
+            None
+        }
+    }
+}
+
+// Check every exercise and print a summary. Returns true if they all pass.
+fn verify(exercises: &[Exercise]) -> bool {
+    let passed = exercises.iter().filter(|exercise| check(exercise)).count();
+
+    println!("\n{}/{} exercises pass", passed, exercises.len());
+    passed == exercises.len()
+}
+
+// Check a single exercise, showing the compiler/program output only when it fails
+fn check(exercise: &Exercise) -> bool {
+    match exercise.check() {
+        Ok(outcome) if outcome.success => {
+            println!("✅ {}", exercise.name);
+            true
+        }
+        Ok(outcome) => {
+            println!("❌ {}\n{}", exercise.name, outcome.output);
+            false
+        }
+        Err(err) => {
+            println!("❌ {}: could not start cargo: {}", exercise.name, err);
+            false
+        }
+    }
+}
+
+fn run(exercise: &Exercise) -> bool {
+    println!("=== Running {} ===", exercise.name);
+    match exercise.run() {
+        Ok(true) => {
+            println!(
+                "\n💡 Typed it all out? Mark it done: cargo run -- done {}",
+                exercise.id
+            );
+            true
+        }
+        Ok(false) => {
+            println!("\n❌ {} did not finish successfully", exercise.name);
+            false
+        }
+        Err(err) => {
+            println!("❌ could not start cargo: {}", err);
+            false
+        }
+    }
+}
+
+// The learner says they've finished. It still has to pass before it's recorded.
+fn done(exercise: &Exercise, progress: &mut Progress) -> bool {
+    if !check(exercise) {
+        println!(
+            "Fix it first, then run `cargo run -- done {}` again.",
+            exercise.id
+        );
+        return false;
+    }
+
+    if progress.mark_complete(&exercise.name) {
+        println!("🎉 {} is marked as done", exercise.name);
+        save(progress);
+    } else {
+        println!("{} was already marked as done", exercise.name);
+    }
+    true
+}
+
+// Losing progress shouldn't stop the exercise itself, so this only warns
+fn save(progress: &Progress) {
+    if let Err(err) = progress.save() {
+        eprintln!("⚠️  Could not save your progress: {}", err);
+    }
+}
+
+// A checklist grouped by chapter, with a percentage for each one:
+//
+//   10  [2/4  50%]
+//       [x] 10_1_Rust_struct
+//       [ ] 10_2_rust_impl
+fn status(exercises: &[Exercise], progress: &Progress) {
+    let mut done_total = 0;
+
+    for topic in exercises.chunk_by(|a, b| a.chapter() == b.chapter()) {
+        let done = topic
+            .iter()
+            .filter(|exercise| progress.is_complete(&exercise.name))
+            .count();
+        done_total += done;
+
+        println!(
+            "{:>3}  [{}/{} {:>3}%]",
+            topic[0].chapter(),
+            done,
+            topic.len(),
+            percent(done, topic.len())
+        );
+        for exercise in topic {
+            let mark = if progress.is_complete(&exercise.name) {
+                'x'
+            } else {
+                ' '
+            };
+            println!("     [{}] {}", mark, exercise.name);
+        }
+    }
+
+    println!(
+        "\n{}/{} exercises complete ({}%)",
+        done_total,
+        exercises.len(),
+        percent(done_total, exercises.len())
+    );
+}
+
+fn percent(done: usize, total: usize) -> usize {
+    (done * 100).checked_div(total).unwrap_or(0)
+}
+
+// Show hint levels 1 through `level`, so earlier hints stay on screen
+fn show_hints(dir: &Path, exercise: &Exercise, level: usize) -> bool {
+    let levels = match hint::load(dir, &exercise.name) {
+        Ok(Some(levels)) if !levels.is_empty() => levels,
+        Ok(_) => {
+            println!("No hints for {} yet.", exercise.name);
+            return true;
+        }
+        Err(err) => {
+            eprintln!("Could not read the hints for {}: {}", exercise.name, err);
+            return false;
+        }
+    };
+
+    for text in levels.iter().take(level) {
+        println!("{}\n", text);
+    }
+
+    if level < levels.len() {
+        println!(
+            "💡 More help: cargo run -- hint {} --level {}",
+            exercise.id,
+            level + 1
+        );
+    } else {
+        println!("That's every hint for {}.", exercise.name);
+    }
+    true
+}
+
+// Poll the files forever, calling `on_change` for each exercise that gets saved
+fn watch_files(exercises: &[Exercise], mut on_change: impl FnMut(&Exercise)) {
+    println!("\n👀 Watching for changes (Ctrl+C to stop)...");
+
+    let mut last_seen: Vec<Option<SystemTime>> =
+        exercises.iter().map(|e| e.modified().ok()).collect();
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        for (exercise, seen) in exercises.iter().zip(last_seen.iter_mut()) {
+            let current = exercise.modified().ok();
+            if current != *seen {
+                *seen = current;
+                println!();
+                on_change(exercise);
+            }
+        }
+    }
 }
@@ -0,0 +1,110 @@
+use rust_tutorial::json::{self, Value};
+use rust_tutorial::save_file;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io;
+use std::path::{Path, PathBuf};
+
+// Which exercises the learner has finished, saved between sessions
+// in .tutor/progress.json:
+//
+//   {
+//     "completed": ["04_hello_world", "13_Rust_Ownership_and_Borrowing"]
+//   }
+pub struct Progress {
+    path: PathBuf,
+    completed: BTreeSet<String>,
+}
+
+impl Progress {
+    // A missing file just means nothing is finished yet
+    pub fn load(path: &Path) -> io::Result<Progress> {
+        let completed = save_file::load(path, parse_completed)?.unwrap_or_default();
+
+        Ok(Progress {
+            path: path.to_path_buf(),
+            completed,
+        })
+    }
+
+    // Creates the .tutor/ directory the first time
+    pub fn save(&self) -> io::Result<()> {
+        let names = self.completed.iter().map(|name| Value::from(name.as_str()));
+        let mut map = BTreeMap::new();
+        map.insert(String::from("completed"), Value::Array(names.collect()));
+
+        save_file::save(&self.path, &Value::Object(map))
+    }
+
+    pub fn is_complete(&self, name: &str) -> bool {
+        self.completed.contains(name)
+    }
+
+    // Returns false if it was already marked, so callers can skip saving
+    pub fn mark_complete(&mut self, name: &str) -> bool {
+        self.completed.insert(name.to_string())
+    }
+
+    // Returns false if there was nothing to reset
+    pub fn reset(&mut self, name: &str) -> bool {
+        self.completed.remove(name)
+    }
+}
+
+fn parse_completed(text: &str) -> Result<BTreeSet<String>, String> {
+    let value = json::parse(text).map_err(|err| err.to_string())?;
+    let items = value
+        .get("completed")
+        .and_then(Value::as_array)
+        .ok_or("expected a \"completed\" list")?;
+
+    items
+        .iter()
+        .map(|item| item.as_str().map(String::from))
+        .collect::<Option<_>>()
+        .ok_or_else(|| String::from("\"completed\" should only hold exercise names"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn temp_file(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("tutor_{}_{}", name, std::process::id()))
+            .join("progress.json")
+    }
+
+    #[test]
+    fn missing_file_means_nothing_completed() {
+        let progress = Progress::load(&temp_file("missing")).unwrap();
+        assert!(!progress.is_complete("13_Rust_Ownership_and_Borrowing"));
+    }
+
+    #[test]
+    fn mark_save_reload_and_reset() {
+        let path = temp_file("round_trip");
+
+        let mut progress = Progress::load(&path).unwrap();
+        assert!(progress.mark_complete("04_hello_world"));
+        assert!(!progress.mark_complete("04_hello_world"));
+        progress.mark_complete("13_Rust_Ownership_and_Borrowing");
+        progress.save().unwrap();
+
+        let mut progress = Progress::load(&path).unwrap();
+        assert!(progress.is_complete("04_hello_world"));
+        assert!(progress.reset("13_Rust_Ownership_and_Borrowing"));
+        assert!(!progress.reset("13_Rust_Ownership_and_Borrowing"));
+        assert!(!progress.is_complete("13_Rust_Ownership_and_Borrowing"));
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn corrupted_files_are_reported() {
+        assert!(parse_completed("{").is_err());
+        assert!(parse_completed("[]").is_err());
+        assert!(parse_completed(r#"{"completed": [1]}"#).is_err());
+        assert_eq!(parse_completed(r#"{"completed": []}"#), Ok(BTreeSet::new()));
+    }
+}
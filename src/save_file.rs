@@ -0,0 +1,80 @@
+//! Loading and saving the JSON files the tutor and the quiz keep in `.tutor/`.
+//!
+//! Each caller brings its own parser from text to its own type; this module only
+//! handles the file itself: a missing file is not an error, a file that doesn't
+//! parse is, and saving creates the directory the first time.
+//!
+//! ```
+//! use rust_tutorial::json::Value;
+//! use rust_tutorial::save_file;
+//!
+//! let path = std::env::temp_dir()
+//!     .join(format!("save_file_doc_{}", std::process::id()))
+//!     .join("count.json");
+//! let parse = |text: &str| text.trim().parse::<u32>().map_err(|err| err.to_string());
+//!
+//! assert_eq!(save_file::load(&path, parse).unwrap(), None);
+//! save_file::save(&path, &Value::from(3u32)).unwrap();
+//! assert_eq!(save_file::load(&path, parse).unwrap(), Some(3));
+//! # std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+//! ```
+
+use crate::json::Value;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Reads `path` and parses it with `parse`.
+///
+/// Returns `Ok(None)` if the file doesn't exist yet. A parse error becomes an
+/// [`io::ErrorKind::InvalidData`] error that names the file.
+pub fn load<T>(
+    path: &Path,
+    parse: impl FnOnce(&str) -> Result<T, String>,
+) -> io::Result<Option<T>> {
+    match fs::read_to_string(path) {
+        Ok(text) => parse(&text).map(Some).map_err(|message| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{} is corrupted: {}", path.display(), message),
+            )
+        }),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Writes `value` as pretty-printed JSON, creating the parent directory if needed.
+pub fn save(path: &Path, value: &Value) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, value.to_pretty_string() + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_number(text: &str) -> Result<f64, String> {
+        crate::json::parse(text)
+            .map_err(|err| err.to_string())?
+            .as_f64()
+            .ok_or_else(|| String::from("not a number"))
+    }
+
+    #[test]
+    fn corrupted_files_name_the_file() {
+        let path = std::env::temp_dir().join(format!("save_file_{}.json", std::process::id()));
+        fs::write(&path, "\"text\"").unwrap();
+
+        let err = load(&path, parse_number).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            err.to_string(),
+            format!("{} is corrupted: not a number", path.display())
+        );
+
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,300 @@
+//! The `Shape` enum from file 11, grown into a small library.
+//!
+//! Shapes can be parsed from text like `"circle 5.0"`, which is how
+//! `examples/11_2_rust_shapes.rs` reads them from stdin.
+//!
+//! ```
+//! use rust_tutorial::shapes::{self, Shape};
+//!
+//! let shapes: Vec<Shape> = ["square 2", "rectangle 3 1"]
+//!     .iter()
+//!     .map(|line| line.parse().unwrap())
+//!     .collect();
+//! assert_eq!(shapes::total_area(&shapes), 7.0);
+//! ```
+
+use std::f64::consts::PI;
+use std::fmt;
+use std::str::FromStr;
+
+/// A flat shape. Sizes are never negative when parsed from text.
+///
+/// Triangles are isosceles: `height` is measured from the middle of `base`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Shape {
+    Circle { radius: f64 },
+    Rectangle { width: f64, height: f64 },
+    Triangle { base: f64, height: f64 },
+}
+
+impl Shape {
+    /// A rectangle with equal sides.
+    pub fn square(side: f64) -> Shape {
+        Shape::Rectangle {
+            width: side,
+            height: side,
+        }
+    }
+
+    pub fn area(&self) -> f64 {
+        match self {
+            Shape::Circle { radius } => PI * radius * radius,
+            Shape::Rectangle { width, height } => width * height,
+            Shape::Triangle { base, height } => 0.5 * base * height,
+        }
+    }
+
+    /// The distance around the outside.
+    ///
+    /// ```
+    /// use rust_tutorial::shapes::Shape;
+    ///
+    /// assert_eq!(Shape::square(2.0).perimeter(), 8.0);
+    /// // Two slanted sides of 5, since 3² + 4² = 5²
+    /// assert_eq!(Shape::Triangle { base: 6.0, height: 4.0 }.perimeter(), 16.0);
+    /// ```
+    pub fn perimeter(&self) -> f64 {
+        match self {
+            Shape::Circle { radius } => 2.0 * PI * radius,
+            Shape::Rectangle { width, height } => 2.0 * (width + height),
+            Shape::Triangle { base, height } => base + 2.0 * (base / 2.0).hypot(*height),
+        }
+    }
+}
+
+/// Writes the shape the way [`FromStr`] reads it, e.g. `circle 5`.
+impl fmt::Display for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Shape::Circle { radius } => write!(f, "circle {}", radius),
+            Shape::Rectangle { width, height } => write!(f, "rectangle {} {}", width, height),
+            Shape::Triangle { base, height } => write!(f, "triangle {} {}", base, height),
+        }
+    }
+}
+
+/// Parses a name followed by its sizes, separated by spaces:
+/// `circle <radius>`, `rectangle <width> <height>`, `square <side>` or
+/// `triangle <base> <height>`.
+///
+/// ```
+/// use rust_tutorial::shapes::{ParseShapeError, Shape};
+///
+/// assert_eq!("circle 5.0".parse(), Ok(Shape::Circle { radius: 5.0 }));
+/// assert_eq!("Square 2".parse(), Ok(Shape::square(2.0)));
+/// assert_eq!(
+///     "hexagon 1".parse::<Shape>(),
+///     Err(ParseShapeError::UnknownShape(String::from("hexagon")))
+/// );
+/// ```
+impl FromStr for Shape {
+    type Err = ParseShapeError;
+
+    fn from_str(text: &str) -> Result<Shape, ParseShapeError> {
+        let mut words = text.split_whitespace();
+        let name = words.next().ok_or(ParseShapeError::Empty)?.to_lowercase();
+        let expected = match name.as_str() {
+            "circle" | "square" => 1,
+            "rectangle" | "triangle" => 2,
+            _ => return Err(ParseShapeError::UnknownShape(name)),
+        };
+        let sizes = words.map(parse_size).collect::<Result<Vec<f64>, _>>()?;
+
+        match (name.as_str(), sizes.as_slice()) {
+            ("circle", &[radius]) => Ok(Shape::Circle { radius }),
+            ("square", &[side]) => Ok(Shape::square(side)),
+            ("rectangle", &[width, height]) => Ok(Shape::Rectangle { width, height }),
+            ("triangle", &[base, height]) => Ok(Shape::Triangle { base, height }),
+            _ => Err(ParseShapeError::WrongSizeCount {
+                found: sizes.len(),
+                shape: name,
+                expected,
+            }),
+        }
+    }
+}
+
+fn parse_size(word: &str) -> Result<f64, ParseShapeError> {
+    match word.parse::<f64>() {
+        Ok(size) if size.is_finite() && size >= 0.0 => Ok(size),
+        _ => Err(ParseShapeError::InvalidSize(word.to_string())),
+    }
+}
+
+/// Why a line of text isn't a [`Shape`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseShapeError {
+    /// Nothing but whitespace.
+    Empty,
+    UnknownShape(String),
+    WrongSizeCount {
+        shape: String,
+        expected: usize,
+        found: usize,
+    },
+    /// Not a number, or a negative, infinite or NaN one.
+    InvalidSize(String),
+}
+
+impl fmt::Display for ParseShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseShapeError::Empty => write!(f, "no shape given"),
+            ParseShapeError::UnknownShape(name) => write!(
+                f,
+                "unknown shape '{}' (try circle, rectangle, square or triangle)",
+                name
+            ),
+            ParseShapeError::WrongSizeCount {
+                shape,
+                expected,
+                found,
+            } => write!(f, "a {} needs {} size(s), got {}", shape, expected, found),
+            ParseShapeError::InvalidSize(word) => write!(f, "'{}' is not a valid size", word),
+        }
+    }
+}
+
+impl std::error::Error for ParseShapeError {}
+
+/// The combined area of all the shapes. Zero for an empty slice.
+pub fn total_area(shapes: &[Shape]) -> f64 {
+    // Not .sum(): summing no floats gives -0.0, which would print as "-0.00"
+    shapes
+        .iter()
+        .map(Shape::area)
+        .fold(0.0, |total, area| total + area)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_every_shape() {
+        assert_eq!("circle 2.5".parse(), Ok(Shape::Circle { radius: 2.5 }));
+        assert_eq!(
+            "  rectangle   10 5 ".parse(),
+            Ok(Shape::Rectangle {
+                width: 10.0,
+                height: 5.0
+            })
+        );
+        assert_eq!("SQUARE 3".parse(), Ok(Shape::square(3.0)));
+        assert_eq!(
+            "triangle 6 12".parse(),
+            Ok(Shape::Triangle {
+                base: 6.0,
+                height: 12.0
+            })
+        );
+        assert_eq!("circle 0".parse(), Ok(Shape::Circle { radius: 0.0 }));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let parse = |text: &str| text.parse::<Shape>().unwrap_err();
+
+        assert_eq!(parse(""), ParseShapeError::Empty);
+        assert_eq!(parse("   "), ParseShapeError::Empty);
+        assert_eq!(
+            parse("hexagon 2"),
+            ParseShapeError::UnknownShape("hexagon".into())
+        );
+        assert_eq!(
+            parse("rectangle 2"),
+            ParseShapeError::WrongSizeCount {
+                shape: "rectangle".into(),
+                expected: 2,
+                found: 1
+            }
+        );
+        assert_eq!(
+            parse("circle"),
+            ParseShapeError::WrongSizeCount {
+                shape: "circle".into(),
+                expected: 1,
+                found: 0
+            }
+        );
+        assert_eq!(
+            parse("circle five"),
+            ParseShapeError::InvalidSize("five".into())
+        );
+        assert_eq!(
+            parse("square -1"),
+            ParseShapeError::InvalidSize("-1".into())
+        );
+        assert_eq!(
+            parse("circle NaN"),
+            ParseShapeError::InvalidSize("NaN".into())
+        );
+        assert_eq!(
+            parse("circle inf"),
+            ParseShapeError::InvalidSize("inf".into())
+        );
+    }
+
+    #[test]
+    fn display_parses_back_to_the_same_shape() {
+        let shapes = [
+            Shape::Circle { radius: 1.5 },
+            Shape::Rectangle {
+                width: 2.0,
+                height: 0.25,
+            },
+            Shape::Triangle {
+                base: 3.0,
+                height: 4.0,
+            },
+        ];
+        for shape in shapes {
+            assert_eq!(shape.to_string().parse(), Ok(shape));
+        }
+        assert_eq!(Shape::square(3.0).to_string(), "rectangle 3 3");
+    }
+
+    #[test]
+    fn areas_and_perimeters() {
+        let circle = Shape::Circle { radius: 1.0 };
+        assert_eq!(circle.area(), PI);
+        assert_eq!(circle.perimeter(), 2.0 * PI);
+
+        let rectangle = Shape::Rectangle {
+            width: 10.0,
+            height: 5.0,
+        };
+        assert_eq!(rectangle.area(), 50.0);
+        assert_eq!(rectangle.perimeter(), 30.0);
+
+        let flat = Shape::Triangle {
+            base: 4.0,
+            height: 0.0,
+        };
+        assert_eq!(flat.area(), 0.0);
+        assert_eq!(flat.perimeter(), 8.0);
+    }
+
+    #[test]
+    fn total_area_adds_up() {
+        assert!(total_area(&[]).is_sign_positive());
+        assert_eq!(total_area(&[Shape::square(2.0), Shape::square(3.0)]), 13.0);
+    }
+
+    #[test]
+    fn error_messages() {
+        assert_eq!(
+            ParseShapeError::WrongSizeCount {
+                shape: "triangle".into(),
+                expected: 2,
+                found: 3
+            }
+            .to_string(),
+            "a triangle needs 2 size(s), got 3"
+        );
+        assert_eq!(
+            ParseShapeError::InvalidSize("x".into()).to_string(),
+            "'x' is not a valid size"
+        );
+    }
+}
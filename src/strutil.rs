@@ -0,0 +1,228 @@
+//! String helpers that never panic on non-ASCII text.
+//!
+//! `&text[0..2]` indexes by *bytes*. In `"héllo"` the `é` takes two bytes, so byte 2
+//! falls in the middle of it and the slice panics. The helpers here return `None`
+//! instead, or count in characters rather than bytes.
+//!
+//! ```
+//! use rust_tutorial::strutil;
+//!
+//! let text = "héllo";
+//! assert_eq!(text.len(), 6); // bytes, not characters
+//! assert_eq!(strutil::safe_slice(text, 0..2), None);
+//! assert_eq!(strutil::safe_slice(text, 0..3), Some("hé"));
+//! assert_eq!(strutil::char_slice(text, 0..2), Some("hé"));
+//! ```
+
+use std::ops::Range;
+
+/// Slices `text` by byte offsets, returning `None` instead of panicking when the range
+/// is out of bounds or cuts a character in half.
+///
+/// ```
+/// use rust_tutorial::strutil::safe_slice;
+///
+/// assert_eq!(safe_slice("Rust", 0..2), Some("Ru"));
+/// assert_eq!(safe_slice("Rust", 2..10), None);
+/// assert_eq!(safe_slice("héllo", 1..2), None); // inside the 'é'
+/// ```
+pub fn safe_slice(text: &str, range: Range<usize>) -> Option<&str> {
+    text.get(range)
+}
+
+/// The character at `index`, counting characters rather than bytes.
+///
+/// ```
+/// use rust_tutorial::strutil::char_at;
+///
+/// assert_eq!(char_at("héllo", 1), Some('é'));
+/// assert_eq!(char_at("héllo", 5), None);
+/// ```
+pub fn char_at(text: &str, index: usize) -> Option<char> {
+    text.chars().nth(index)
+}
+
+/// Slices `text` by character positions: `char_slice("héllo", 1..3)` is the 2nd and 3rd
+/// characters. Returns `None` if the range goes past the end or is backwards.
+///
+/// ```
+/// use rust_tutorial::strutil::char_slice;
+///
+/// assert_eq!(char_slice("héllo", 1..3), Some("él"));
+/// assert_eq!(char_slice("héllo", 3..5), Some("lo"));
+/// assert_eq!(char_slice("héllo", 4..9), None);
+/// ```
+pub fn char_slice(text: &str, range: Range<usize>) -> Option<&str> {
+    if range.start > range.end {
+        return None;
+    }
+    let start = byte_offset(text, range.start)?;
+    let end = byte_offset(text, range.end)?;
+    text.get(start..end)
+}
+
+// Byte position where the character number `index` starts. `index` may be one past the
+// last character, which is the end of the string.
+fn byte_offset(text: &str, index: usize) -> Option<usize> {
+    text.char_indices()
+        .map(|(offset, _)| offset)
+        .chain(std::iter::once(text.len()))
+        .nth(index)
+}
+
+/// Splits `text` into what a reader would call characters.
+///
+/// A `char` is one Unicode scalar value, but one visible character can be several:
+/// `"e\u{301}"` is an `e` followed by a combining accent, and a family emoji is
+/// several emoji glued together with zero-width joiners.
+///
+/// This is an approximation of the Unicode rules that covers combining marks,
+/// zero-width joiner sequences, variation selectors, skin-tone modifiers, flags
+/// and `\r\n`. For the full rules, use the `unicode-segmentation` crate.
+///
+/// ```
+/// use rust_tutorial::strutil::graphemes;
+///
+/// let text = "he\u{301}llo"; // "héllo" with a combining accent
+/// assert_eq!(text.chars().count(), 6);
+/// assert_eq!(graphemes(text).count(), 5);
+/// assert_eq!(graphemes(text).nth(1), Some("e\u{301}"));
+/// ```
+pub fn graphemes(text: &str) -> Graphemes<'_> {
+    Graphemes { rest: text }
+}
+
+/// Number of visible characters, see [`graphemes`].
+///
+/// ```
+/// use rust_tutorial::strutil::grapheme_count;
+///
+/// assert_eq!(grapheme_count("héllo"), 5);
+/// assert_eq!(grapheme_count("🇯🇵👍🏽"), 2); // a flag and a thumbs-up with skin tone
+/// ```
+pub fn grapheme_count(text: &str) -> usize {
+    graphemes(text).count()
+}
+
+/// Keeps at most `max` visible characters, never splitting one in half.
+///
+/// ```
+/// use rust_tutorial::strutil::truncate_graphemes;
+///
+/// assert_eq!(truncate_graphemes("he\u{301}llo", 2), "he\u{301}");
+/// assert_eq!(truncate_graphemes("hi", 10), "hi");
+/// ```
+pub fn truncate_graphemes(text: &str, max: usize) -> &str {
+    let end: usize = graphemes(text).take(max).map(str::len).sum();
+    &text[..end]
+}
+
+/// Iterator over the visible characters of a string. Created by [`graphemes`].
+pub struct Graphemes<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let mut chars = self.rest.char_indices();
+        let (_, first) = chars.next()?;
+
+        let mut previous = first;
+        let mut regional_indicators = usize::from(is_regional_indicator(first));
+        let mut end = self.rest.len();
+
+        for (offset, c) in chars {
+            let joins = is_extender(c)
+                || previous == '\u{200D}'
+                || (previous == '\r' && c == '\n')
+                || (is_regional_indicator(c) && regional_indicators == 1);
+            if !joins {
+                end = offset;
+                break;
+            }
+            if is_regional_indicator(c) {
+                regional_indicators += 1;
+            }
+            previous = c;
+        }
+
+        let (grapheme, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        Some(grapheme)
+    }
+}
+
+// Characters that attach to the one before them
+fn is_extender(c: char) -> bool {
+    matches!(c,
+        '\u{0300}'..='\u{036F}'     // combining diacritical marks (accents)
+        | '\u{1AB0}'..='\u{1AFF}'
+        | '\u{1DC0}'..='\u{1DFF}'
+        | '\u{20D0}'..='\u{20FF}'
+        | '\u{FE20}'..='\u{FE2F}'
+        | '\u{FE00}'..='\u{FE0F}'   // variation selectors (text vs emoji style)
+        | '\u{1F3FB}'..='\u{1F3FF}' // skin-tone modifiers
+        | '\u{200D}'                // zero-width joiner
+    )
+}
+
+// Flags are two of these in a row: 🇯 + 🇵 = 🇯🇵
+fn is_regional_indicator(c: char) -> bool {
+    matches!(c, '\u{1F1E6}'..='\u{1F1FF}')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn safe_slice_rejects_cut_characters() {
+        let text = "héllo";
+        assert_eq!(safe_slice(text, 0..1), Some("h"));
+        assert_eq!(safe_slice(text, 0..2), None);
+        assert_eq!(safe_slice(text, 3..6), Some("llo"));
+        assert_eq!(safe_slice(text, 6..6), Some(""));
+        assert_eq!(safe_slice(text, 6..7), None);
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)] // the backwards range is what's being tested
+    fn char_positions() {
+        assert_eq!(char_at("", 0), None);
+        assert_eq!(char_at("日本語", 2), Some('語'));
+
+        assert_eq!(char_slice("日本語", 0..0), Some(""));
+        assert_eq!(char_slice("日本語", 1..3), Some("本語"));
+        assert_eq!(char_slice("日本語", 3..3), Some(""));
+        assert_eq!(char_slice("日本語", 2..1), None);
+        assert_eq!(char_slice("日本語", 0..4), None);
+    }
+
+    #[test]
+    fn combining_marks_and_joiners_stay_together() {
+        let family = "👨\u{200D}👩\u{200D}👧";
+        let pieces: Vec<&str> = graphemes("ae\u{301}\u{302}b").collect();
+
+        assert_eq!(pieces, vec!["a", "e\u{301}\u{302}", "b"]);
+        assert_eq!(grapheme_count(family), 1);
+        assert_eq!(grapheme_count("❤\u{FE0F}!"), 2);
+        assert_eq!(grapheme_count("a\r\nb"), 3);
+    }
+
+    #[test]
+    fn flags_pair_up() {
+        // Three regional indicators: one flag, then a lone indicator
+        let pieces: Vec<&str> = graphemes("🇯🇵🇫").collect();
+        assert_eq!(pieces, vec!["🇯🇵", "🇫"]);
+    }
+
+    #[test]
+    fn truncating() {
+        assert_eq!(truncate_graphemes("", 3), "");
+        assert_eq!(truncate_graphemes("héllo", 0), "");
+        assert_eq!(truncate_graphemes("héllo", 2), "hé");
+        assert_eq!(truncate_graphemes("🇯🇵🇫🇷", 1), "🇯🇵");
+    }
+}